@@ -1,11 +1,14 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use flatgeobuf::{FgbReader, FgbWriter, GeometryType, HttpFgbReader};
 use geozero::csv::{CsvReader, CsvWriter};
 use geozero::error::{GeozeroError, Result};
 use geozero::geojson::{GeoJsonLineReader, GeoJsonReader, GeoJsonWriter};
 use geozero::svg::SvgWriter;
 use geozero::wkt::{WktReader, WktWriter};
-use geozero::{FeatureProcessor, GeozeroDatasource};
+use geozero::{
+    AsyncGeozeroDatasource, Bbox, BboxFilter, BoundsProcessor, DatasetSummary, FeatureProcessor,
+    FeatureRecorder, GeozeroDatasource, GeozeroGeometry, RecordedFeature,
+};
 use std::ffi::OsStr;
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
@@ -13,22 +16,47 @@ use std::num::ParseFloatError;
 use std::path::{Path, PathBuf};
 use std::process::exit;
 
-#[derive(Parser)]
-#[command(about, version)]
+#[derive(Parser, Clone)]
+#[command(
+    about,
+    version,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// When processing CSV, the name of the column holding a WKT geometry.
-    #[arg(long)]
+    #[arg(long, global = true)]
     csv_geometry_column: Option<String>,
 
     /// Geometries within extent
-    #[arg(short, long, value_parser = parse_extent)]
+    #[arg(short, long, global = true, value_parser = parse_extent)]
     extent: Option<Extent>,
 
-    /// The path or URL to the FlatGeobuf file to read
-    input: String,
+    /// The path to the file to read, or the URL of a FlatGeobuf file
+    #[arg(required = true)]
+    input: Option<String>,
 
     /// The path to the file to write
-    dest: PathBuf,
+    #[arg(required = true)]
+    dest: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Clone)]
+enum Command {
+    /// Print a summary of the features, geometries and properties of a dataset
+    Info {
+        /// The path to the file to read, or the URL of a FlatGeobuf file
+        input: String,
+    },
+}
+
+impl Cli {
+    fn input(&self) -> &str {
+        self.input.as_deref().expect("input is required")
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -55,13 +83,13 @@ fn parse_extent(src: &str) -> std::result::Result<Extent, ParseFloatError> {
     })
 }
 
-async fn transform<P: FeatureProcessor>(args: Cli, processor: &mut P) -> Result<()> {
-    let path_in = Path::new(&args.input);
+async fn transform<P: FeatureProcessor + Send>(args: Cli, processor: &mut P) -> Result<()> {
+    let path_in = Path::new(args.input());
     if path_in.starts_with("http:") || path_in.starts_with("https:") {
         if path_in.extension().and_then(OsStr::to_str) != Some("fgb") {
             panic!("Remote access is only supported for .fgb input")
         }
-        let ds = HttpFgbReader::open(&args.input)
+        let ds = HttpFgbReader::open(args.input())
             .await
             .map_err(fgb_to_geozero_err)?;
         let mut ds = if let Some(bbox) = &args.extent {
@@ -73,22 +101,27 @@ async fn transform<P: FeatureProcessor>(args: Cli, processor: &mut P) -> Result<
         };
         ds.process_features(processor).await
     } else {
-        let mut filein = BufReader::new(File::open(path_in)?);
         match path_in.extension().and_then(OsStr::to_str) {
             Some("csv") => {
                 let geometry_column_name = args
                     .csv_geometry_column
+                    .as_deref()
                     .expect("must specify --csv-geometry-column=<column name> when parsing CSV");
-                let mut ds = CsvReader::new(&geometry_column_name, &mut filein);
-                GeozeroDatasource::process(&mut ds, processor)
+                let filein = tokio::io::BufReader::new(tokio::fs::File::open(path_in).await?);
+                let mut ds = CsvReader::new(geometry_column_name, filein);
+                AsyncGeozeroDatasource::process(&mut ds, processor).await
             }
             Some("json") | Some("geojson") => {
+                let filein = BufReader::new(File::open(path_in)?);
                 GeozeroDatasource::process(&mut GeoJsonReader(filein), processor)
             }
             Some("jsonl") | Some("geojsonl") => {
-                GeozeroDatasource::process(&mut GeoJsonLineReader::new(filein), processor)
+                let filein = tokio::fs::File::open(path_in).await?;
+                AsyncGeozeroDatasource::process(&mut GeoJsonLineReader::new(filein), processor)
+                    .await
             }
             Some("fgb") => {
+                let mut filein = BufReader::new(File::open(path_in)?);
                 let ds = FgbReader::open(&mut filein).map_err(fgb_to_geozero_err)?;
                 let mut ds = if let Some(bbox) = &args.extent {
                     ds.select_bbox(bbox.minx, bbox.miny, bbox.maxx, bbox.maxy)
@@ -98,40 +131,100 @@ async fn transform<P: FeatureProcessor>(args: Cli, processor: &mut P) -> Result<
                 };
                 ds.process_features(processor)
             }
-            Some("wkt") => GeozeroDatasource::process(&mut WktReader(&mut filein), processor),
+            Some("wkt") => {
+                let filein = tokio::fs::File::open(path_in).await?;
+                AsyncGeozeroDatasource::process(&mut WktReader(filein), processor).await
+            }
             _ => panic!("Unknown input file extension"),
         }
     }
 }
 
+/// Transform input into `processor`, keeping only features intersecting the given extent
+async fn transform_filtered<P: FeatureProcessor + Send>(args: Cli, mut processor: P) -> Result<P> {
+    // FlatGeobuf input is filtered with its spatial index
+    let fgb_input = Path::new(args.input()).extension().and_then(OsStr::to_str) == Some("fgb");
+    match args.extent {
+        Some(extent) if !fgb_input => {
+            let bbox = Bbox::new(extent.minx, extent.miny, extent.maxx, extent.maxy);
+            let mut filter = BboxFilter::intersecting(processor, bbox);
+            transform(args, &mut filter).await?;
+            Ok(filter.into_inner())
+        }
+        _ => {
+            transform(args, &mut processor).await?;
+            Ok(processor)
+        }
+    }
+}
+
 async fn process(args: Cli) -> Result<()> {
-    let mut fout = BufWriter::new(File::create(&args.dest)?);
-    match args.dest.extension().and_then(OsStr::to_str) {
-        Some("csv") => transform(args, &mut CsvWriter::new(&mut fout)).await?,
-        Some("wkt") => transform(args, &mut WktWriter::new(&mut fout)).await?,
+    let dest = args.dest.clone().expect("dest is required");
+    let mut fout = BufWriter::new(File::create(&dest)?);
+    match dest.extension().and_then(OsStr::to_str) {
+        Some("csv") => {
+            transform_filtered(args, CsvWriter::new(&mut fout)).await?;
+        }
+        Some("wkt") => {
+            transform_filtered(args, WktWriter::new(&mut fout)).await?;
+        }
         Some("json") | Some("geojson") => {
-            transform(args, &mut GeoJsonWriter::new(&mut fout)).await?
+            transform_filtered(args, GeoJsonWriter::new(&mut fout)).await?;
         }
         Some("fgb") => {
-            let mut fgb =
+            let fgb =
                 FgbWriter::create("fgb", GeometryType::Unknown).map_err(fgb_to_geozero_err)?;
-            transform(args, &mut fgb).await?;
+            let fgb = transform_filtered(args, fgb).await?;
             fgb.write(&mut fout).map_err(fgb_to_geozero_err)?;
         }
         Some("svg") => {
             let mut processor = SvgWriter::new(&mut fout, true);
-            set_dimensions(&mut processor, args.extent);
-            transform(args, &mut processor).await?;
+            if args.extent.is_some() {
+                set_dimensions(&mut processor, args.extent);
+                transform_filtered(args, processor).await?;
+            } else {
+                // Buffer the input once, since the extent is needed before writing
+                let mut recorder = FeatureRecorder::new();
+                transform(args, &mut recorder).await?;
+                let features = recorder.into_features();
+                set_dimensions(&mut processor, data_extent(&features)?);
+                processor.dataset_begin(None)?;
+                for feature in &features {
+                    feature.process(&mut processor)?;
+                }
+                processor.dataset_end()?;
+            }
         }
         _ => panic!("Unknown output file extension"),
     }
     Ok(())
 }
+
+async fn info(args: Cli) -> Result<()> {
+    let summary = transform_filtered(args, DatasetSummary::new()).await?;
+    print!("{summary}");
+    Ok(())
+}
+
+/// Full extent of the recorded features
+fn data_extent(features: &[RecordedFeature]) -> Result<Option<Extent>> {
+    let mut bounds = BoundsProcessor::new();
+    for feature in features {
+        feature.process_geom(&mut bounds)?;
+    }
+    Ok(bounds.bbox().map(|bbox| Extent {
+        minx: bbox.minx,
+        miny: bbox.miny,
+        maxx: bbox.maxx,
+        maxy: bbox.maxy,
+    }))
+}
+
 fn set_dimensions(processor: &mut SvgWriter<&mut BufWriter<File>>, extent: Option<Extent>) {
     if let Some(extent) = extent {
         processor.set_dimensions(extent.minx, extent.miny, extent.maxx, extent.maxy, 800, 600);
     } else {
-        // TODO: get image size as opts and full extent from data
+        // TODO: get image size as opts
         processor.set_dimensions(-180.0, -90.0, 180.0, 90.0, 800, 600);
     }
 }
@@ -162,7 +255,16 @@ async fn main() {
 
     let args = Cli::parse();
 
-    let result = process(args).await;
+    let result = match args.command.clone() {
+        Some(Command::Info { input }) => {
+            info(Cli {
+                input: Some(input),
+                ..args
+            })
+            .await
+        }
+        None => process(args).await,
+    };
 
     if let Err(msg) = result {
         println!("Processing failed: {msg}");
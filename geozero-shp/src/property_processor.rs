@@ -41,9 +41,7 @@ impl FeatureProperties for ShapeRecord {
                 | FieldValue::Numeric(None)
                 | FieldValue::Logical(None)
                 | FieldValue::Date(None)
-                | FieldValue::Float(None) => {
-                    continue; // Ignore NULL values
-                }
+                | FieldValue::Float(None) => processor.property(i, name, &ColumnValue::Null)?,
             };
             if finish {
                 return Ok(true);
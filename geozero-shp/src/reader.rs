@@ -1,8 +1,12 @@
+use crate::header::ShapeType;
 use crate::shp_reader::{read_shape, RecordHeader};
 use crate::shx_reader::{read_index_file, ShapeIndex};
 use crate::{header, Error};
 pub use dbase::{FieldInfo, FieldType};
-use geozero::{FeatureProcessor, FeatureProperties, GeomProcessor};
+use geozero::{
+    Column, ColumnType, CoordDimensions, FeatureProcessor, FeatureProperties, GeomProcessor,
+    GeometryType, Schema,
+};
 use std::fs::File;
 use std::io::{BufReader, Read, Seek};
 use std::iter::FusedIterator;
@@ -39,6 +43,7 @@ impl<'a, P: FeatureProcessor, T: Read + Seek + 'a> FusedIterator for ShapeIterat
 pub struct ShapeRecordIterator<'a, P: FeatureProcessor, T: Read + Seek> {
     shape_iter: ShapeIterator<'a, P, T>,
     dbf_reader: dbase::Reader<T>,
+    schema: Schema,
     featno: u64,
 }
 
@@ -52,6 +57,9 @@ impl<'a, P: FeatureProcessor, T: Read + Seek + 'a> Iterator for ShapeRecordItera
     fn next(&mut self) -> Option<Self::Item> {
         if self.featno == 0 {
             self.shape_iter.processor.dataset_begin(None).ok();
+            if let Err(e) = self.shape_iter.processor.schema(&self.schema) {
+                return Some(Err(Error::GeozeroError(e)));
+            }
         }
         let record = match self.dbf_reader.iter_records().next() {
             None => {
@@ -90,6 +98,47 @@ impl<'a, P: FeatureProcessor, T: Read + Seek + 'a> Iterator for ShapeRecordItera
 
 impl<'a, P: FeatureProcessor, T: Read + Seek + 'a> FusedIterator for ShapeRecordIterator<'a, P, T> {}
 
+/// Schema declared by the shapefile header and the DBF fields
+fn dataset_schema(shape_type: ShapeType, fields: &[FieldInfo]) -> Schema {
+    let columns = fields
+        .iter()
+        .filter(|f| f.name() != "DeletionFlag")
+        .map(|f| {
+            let column_type = match f.field_type() {
+                FieldType::Character | FieldType::Memo => ColumnType::String,
+                FieldType::Numeric | FieldType::Double | FieldType::Currency => ColumnType::Double,
+                FieldType::Float => ColumnType::Float,
+                FieldType::Integer => ColumnType::Int,
+                FieldType::Logical => ColumnType::Bool,
+                FieldType::Date | FieldType::DateTime => ColumnType::DateTime,
+            };
+            Column::new(f.name(), Some(column_type))
+        })
+        .collect();
+    let geometry_type = match shape_type {
+        ShapeType::Point | ShapeType::PointZ | ShapeType::PointM => GeometryType::Point,
+        ShapeType::Multipoint | ShapeType::MultipointZ | ShapeType::MultipointM => {
+            GeometryType::MultiPoint
+        }
+        ShapeType::Polyline | ShapeType::PolylineZ | ShapeType::PolylineM => {
+            GeometryType::MultiLineString
+        }
+        ShapeType::Polygon | ShapeType::PolygonZ | ShapeType::PolygonM => {
+            GeometryType::MultiPolygon
+        }
+        ShapeType::Multipatch | ShapeType::NullShape => GeometryType::Unknown,
+    };
+    Schema {
+        columns,
+        geometry_type,
+        dims: CoordDimensions {
+            z: shape_type.has_z(),
+            m: shape_type.has_m(),
+            ..Default::default()
+        },
+    }
+}
+
 /// struct that reads the content of a shapefile
 pub struct Reader<T: Read + Seek> {
     source: T,
@@ -164,10 +213,12 @@ impl<T: Read + Seek> Reader<T> {
     ) -> Result<ShapeRecordIterator<P, T>, Error> {
         let maybe_dbf_reader = self.dbf_reader.take();
         if let Some(dbf_reader) = maybe_dbf_reader {
+            let schema = dataset_schema(self.header.shape_type, dbf_reader.fields());
             let shape_iter = self.iter_geometries(processor);
             Ok(ShapeRecordIterator {
                 shape_iter,
                 dbf_reader,
+                schema,
                 featno: 0,
             })
         } else {
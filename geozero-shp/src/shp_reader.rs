@@ -1,6 +1,6 @@
 use crate::{Error, ShapeType};
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
-use geozero::GeomProcessor;
+use geozero::{GeomProcessor, Winding};
 use std::io::Read;
 use std::mem::size_of;
 
@@ -416,15 +416,8 @@ enum RingType {
 ///
 /// https://stackoverflow.com/questions/1165647/how-to-determine-if-a-list-of-polygon-points-are-in-clockwise-order/1180256#1180256
 fn ring_type_from_points_ordering(points: &[Coord]) -> RingType {
-    let area = points
-        .windows(2)
-        .map(|pts| (pts[1].x - pts[0].x) * (pts[1].y + pts[0].y))
-        .sum::<f64>()
-        / 2.0f64;
-
-    if area < 0.0 {
-        RingType::InnerRing
-    } else {
-        RingType::OuterRing
+    match Winding::of_ring(points.iter().map(|p| (p.x, p.y))) {
+        Some(Winding::CounterClockwise) => RingType::InnerRing,
+        _ => RingType::OuterRing,
     }
 }
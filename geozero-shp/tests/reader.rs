@@ -1,7 +1,10 @@
 use dbase::FieldValue;
 use geozero::geojson::GeoJsonWriter;
 use geozero::wkt::WktWriter;
-use geozero::{CoordDimensions, FeatureProperties, ProcessorSink};
+use geozero::{
+    Bbox, Column, ColumnType, CoordDimensions, DatasetSummary, FeatureProcessor, FeatureProperties,
+    GeomProcessor, GeometryType, ProcessorSink, PropertyProcessor, Schema,
+};
 use std::fs::File;
 use std::io::BufReader;
 use std::str::from_utf8;
@@ -163,6 +166,64 @@ fn property_file() -> Result<(), geozero_shp::Error> {
     Ok(())
 }
 
+#[test]
+fn dataset_schema() -> Result<(), geozero_shp::Error> {
+    #[derive(Default)]
+    struct SchemaReader(Option<Schema>);
+    impl FeatureProcessor for SchemaReader {
+        fn schema(&mut self, schema: &Schema) -> geozero::error::Result<()> {
+            self.0 = Some(schema.clone());
+            Ok(())
+        }
+    }
+    impl GeomProcessor for SchemaReader {}
+    impl PropertyProcessor for SchemaReader {}
+
+    let reader = geozero_shp::Reader::from_path("./tests/data/poly.shp")?;
+    let mut processor = SchemaReader::default();
+    assert_eq!(reader.iter_features(&mut processor)?.count(), 10);
+    let schema = processor.0.unwrap();
+    assert_eq!(
+        schema.columns,
+        vec![
+            Column::new("AREA", Some(ColumnType::Double)),
+            Column::new("EAS_ID", Some(ColumnType::Double)),
+            Column::new("PRFEDEA", Some(ColumnType::String)),
+        ]
+    );
+    assert_eq!(schema.geometry_type, GeometryType::MultiPolygon);
+    assert_eq!(schema.dims, CoordDimensions::xy());
+    Ok(())
+}
+
+#[test]
+fn dataset_summary() -> Result<(), geozero_shp::Error> {
+    let reader = geozero_shp::Reader::from_path("./tests/data/poly.shp")?;
+    let [minx, maxx] = reader.header().bbox.x_range();
+    let [miny, maxy] = reader.header().bbox.y_range();
+    let mut summary = DatasetSummary::new();
+    assert_eq!(reader.iter_features(&mut summary)?.count(), 10);
+    assert_eq!(summary.feature_count, 10);
+    assert_eq!(
+        summary.bounds.unwrap().bbox,
+        Bbox::new(minx, miny, maxx, maxy)
+    );
+    let columns: Vec<_> = summary
+        .properties
+        .iter()
+        .map(|prop| (prop.name.as_str(), prop.column_type))
+        .collect();
+    assert_eq!(
+        columns,
+        [
+            ("AREA", Some(ColumnType::Double)),
+            ("EAS_ID", Some(ColumnType::Double)),
+            ("PRFEDEA", Some(ColumnType::String)),
+        ]
+    );
+    Ok(())
+}
+
 #[test]
 fn point() -> Result<(), geozero_shp::Error> {
     let reader = geozero_shp::Reader::from_path("./tests/data/point.shp")?;
@@ -0,0 +1,433 @@
+use crate::clip::{clip_polygon, Geom, GeomBuilder};
+use crate::coord::Coord;
+use crate::error::{GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::PropertyProcessor;
+use crate::Bbox;
+
+/// Segment from `a` to `b` crosses the antimeridian
+fn crosses(a: &Coord, b: &Coord) -> bool {
+    (b.x - a.x).abs() > 180.0
+}
+
+// `Option::is_none_or` requires Rust 1.82
+#[allow(clippy::unnecessary_map_or)]
+fn push_distinct(coords: &mut Vec<Coord>, coord: Coord) {
+    if coords
+        .last()
+        .map_or(true, |last| last.x != coord.x || last.y != coord.y)
+    {
+        coords.push(coord);
+    }
+}
+
+/// Parts of a linestring on either side of the antimeridian
+fn split_line(coords: &[Coord]) -> Vec<Vec<Coord>> {
+    let mut parts = Vec::new();
+    let mut part: Vec<Coord> = Vec::new();
+    for (i, coord) in coords.iter().enumerate() {
+        if i > 0 && crosses(&coords[i - 1], coord) {
+            let prev = &coords[i - 1];
+            // Cross at the meridian on the side of `prev`
+            let edge = if coord.x > prev.x { -180.0 } else { 180.0 };
+            let unwrapped = Coord {
+                x: coord.x + 2.0 * edge,
+                ..*coord
+            };
+            let cross = prev.interpolate(&unwrapped, (edge - prev.x) / (unwrapped.x - prev.x));
+            push_distinct(&mut part, Coord { x: edge, ..cross });
+            parts.push(std::mem::take(&mut part));
+            part.push(Coord { x: -edge, ..cross });
+        }
+        push_distinct(&mut part, *coord);
+    }
+    parts.push(part);
+    parts.retain(|part| part.len() > 1);
+    parts
+}
+
+/// Ring with continuous longitudes, without jumps at the antimeridian
+fn unwrap_ring(ring: &[Coord]) -> Vec<Coord> {
+    let mut offset = 0.0;
+    let mut unwrapped: Vec<Coord> = Vec::with_capacity(ring.len());
+    for (i, coord) in ring.iter().enumerate() {
+        if i > 0 && crosses(&ring[i - 1], coord) {
+            offset += if coord.x > ring[i - 1].x {
+                -360.0
+            } else {
+                360.0
+            };
+        }
+        unwrapped.push(Coord {
+            x: coord.x + offset,
+            ..*coord
+        });
+    }
+    unwrapped
+}
+
+fn center_x(ring: &[Coord]) -> f64 {
+    let (minx, maxx) = ring.iter().fold((f64::MAX, f64::MIN), |(minx, maxx), c| {
+        (minx.min(c.x), maxx.max(c.x))
+    });
+    (minx + maxx) / 2.0
+}
+
+/// Parts of a polygon on either side of the antimeridian
+///
+/// Polygons with an exterior ring around a pole are returned unchanged.
+fn split_polygon(rings: Vec<Vec<Coord>>) -> Vec<Vec<Vec<Coord>>> {
+    let crossing = rings
+        .iter()
+        .any(|ring| ring.windows(2).any(|s| crosses(&s[0], &s[1])));
+    let Some(exterior) = rings
+        .first()
+        .filter(|_| crossing)
+        .map(|ring| unwrap_ring(ring))
+    else {
+        return vec![rings];
+    };
+    if exterior.first().map(|c| c.x) != exterior.last().map(|c| c.x) {
+        return vec![rings];
+    }
+    // Move holes next to the unwrapped exterior
+    let center = center_x(&exterior);
+    let unwrapped: Vec<Vec<Coord>> = std::iter::once(exterior)
+        .chain(rings[1..].iter().map(|ring| {
+            let ring = unwrap_ring(ring);
+            let offset = ((center - center_x(&ring)) / 360.0).round() * 360.0;
+            ring.into_iter()
+                .map(|c| Coord {
+                    x: c.x + offset,
+                    ..c
+                })
+                .collect()
+        }))
+        .collect();
+    let mut parts = Vec::new();
+    for offset in [0.0, -360.0, 360.0] {
+        let bbox = Bbox::new(
+            -180.0 + offset,
+            f64::NEG_INFINITY,
+            180.0 + offset,
+            f64::INFINITY,
+        );
+        if let Some(mut part) = clip_polygon(&unwrapped, &bbox) {
+            for c in part.iter_mut().flatten() {
+                c.x -= offset;
+            }
+            parts.push(part);
+        }
+    }
+    parts
+}
+
+fn split(geom: Geom) -> Geom {
+    match geom {
+        Geom::LineString(coords) => {
+            let mut parts = split_line(&coords);
+            match parts.len() {
+                0 => Geom::LineString(coords),
+                1 => Geom::LineString(parts.remove(0)),
+                _ => Geom::MultiLineString(parts),
+            }
+        }
+        Geom::MultiLineString(lines) => Geom::MultiLineString(
+            lines
+                .into_iter()
+                .flat_map(|line| {
+                    let parts = split_line(&line);
+                    if parts.is_empty() {
+                        vec![line]
+                    } else {
+                        parts
+                    }
+                })
+                .collect(),
+        ),
+        Geom::Polygon(rings) => {
+            let mut parts = split_polygon(rings);
+            if parts.len() == 1 {
+                Geom::Polygon(parts.remove(0))
+            } else {
+                Geom::MultiPolygon(parts)
+            }
+        }
+        Geom::MultiPolygon(polygons) => {
+            Geom::MultiPolygon(polygons.into_iter().flat_map(split_polygon).collect())
+        }
+        Geom::GeometryCollection(members) => {
+            Geom::GeometryCollection(members.into_iter().map(split).collect())
+        }
+        geom => geom,
+    }
+}
+
+/// Processor cutting geographic geometries at the antimeridian
+///
+/// Each geometry is buffered, and linestrings and polygons with segments crossing ±180° longitude
+/// are cut into parts on either side, as recommended by RFC 7946. Lines and polygons which are
+/// split are passed on as multi-geometries. Longitudes are expected in the range [-180, 180].
+///
+/// Polygons around a pole are passed unchanged. Curves are linearized by readers, other curve,
+/// triangle and surface events are rejected.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::{AntimeridianSplit, GeozeroGeometry};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = AntimeridianSplit::new(WktWriter::new(&mut out));
+/// Wkt("LINESTRING(170 10,-170 20)").process_geom(&mut processor).unwrap();
+/// assert_eq!(
+///     std::str::from_utf8(&out).unwrap(),
+///     "MULTILINESTRING((170 10,180 15),(-180 15,-170 20))"
+/// );
+/// # }
+/// ```
+pub struct AntimeridianSplit<P> {
+    inner: P,
+    geom: GeomBuilder,
+}
+
+impl<P: GeomProcessor> AntimeridianSplit<P> {
+    pub fn new(inner: P) -> Self {
+        AntimeridianSplit {
+            inner,
+            geom: GeomBuilder::default(),
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Split and emit a completed top level geometry
+    fn emit(&mut self, geom: Option<Geom>, idx: usize) -> Result<()> {
+        match geom {
+            Some(geom) => {
+                let multi_dim = self.inner.multi_dim();
+                split(geom).process(&mut self.inner, multi_dim, idx)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn unsupported(&self, geometry_type: &str) -> Result<()> {
+        Err(GeozeroError::Geometry(format!(
+            "splitting {geometry_type} at the antimeridian is not supported"
+        )))
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for AntimeridianSplit<P> {
+    forward!(
+        inner => dataset_begin, schema, dataset_end, feature_begin, feature_end, properties_begin,
+        properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for AntimeridianSplit<P> {
+    forward!(inner => property);
+}
+
+impl<P: GeomProcessor> GeomProcessor for AntimeridianSplit<P> {
+    fn supports_curves(&self) -> bool {
+        false
+    }
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.geom.coord(Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+            t: None,
+            tm: None,
+        })
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        self.geom.coord(Coord { x, y, z, m, t, tm })
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.add(Geom::Point(None))?;
+        self.emit(geom, idx)
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.geom.begin(Geom::Point(None));
+        Ok(())
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom.begin(Geom::MultiPoint(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.geom.line_begin(size);
+        Ok(())
+    }
+    fn linestring_end(&mut self, _tagged: bool, idx: usize) -> Result<()> {
+        let geom = self.geom.line_end()?;
+        self.emit(geom, idx)
+    }
+    fn multilinestring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom
+            .begin(Geom::MultiLineString(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn polygon_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.geom.begin(Geom::Polygon(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn polygon_end(&mut self, _tagged: bool, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom
+            .begin(Geom::MultiPolygon(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom
+            .begin(Geom::GeometryCollection(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn circularstring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("circularstring")
+    }
+    fn compoundcurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("compoundcurve")
+    }
+    fn curvepolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("curvepolygon")
+    }
+    fn multicurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("multicurve")
+    }
+    fn multisurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("multisurface")
+    }
+    fn triangle_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("triangle")
+    }
+    fn polyhedralsurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("polyhedralsurface")
+    }
+    fn tin_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("tin")
+    }
+    forward!(inner => dimensions, multi_dim, srid);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "with-wkt")]
+    fn split_wkt(wkt: &str) -> String {
+        use crate::wkt::{Wkt, WktWriter};
+        use crate::GeozeroGeometry;
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = AntimeridianSplit::new(WktWriter::new(&mut out));
+        Wkt(wkt).process_geom(&mut processor).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn lines() {
+        assert_eq!(
+            split_wkt("LINESTRING(170 0,179 0,-179 0)"),
+            "MULTILINESTRING((170 0,179 0,180 0),(-180 0,-179 0))"
+        );
+        // Crossing twice, eastwards and westwards
+        assert_eq!(
+            split_wkt("LINESTRING(-170 0,170 10,-170 20)"),
+            "MULTILINESTRING((-170 0,-180 5),(180 5,170 10,180 15),(-180 15,-170 20))"
+        );
+        // Starting on the antimeridian
+        assert_eq!(
+            split_wkt("LINESTRING(180 0,-170 0)"),
+            "LINESTRING(-180 0,-170 0)"
+        );
+        assert_eq!(split_wkt("LINESTRING(0 0,10 10)"), "LINESTRING(0 0,10 10)");
+        assert_eq!(
+            split_wkt("MULTILINESTRING((170 0,-170 0),(0 0,10 10))"),
+            "MULTILINESTRING((170 0,180 0),(-180 0,-170 0),(0 0,10 10))"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn polygons() {
+        assert_eq!(
+            split_wkt("POLYGON((170 0,-170 0,-170 10,170 10,170 0))"),
+            "MULTIPOLYGON(((170 0,180 0,180 10,170 10,170 0)),((-180 0,-170 0,-170 10,-180 10,-180 0)))"
+        );
+        // Hole on the other side of the antimeridian
+        assert_eq!(
+            split_wkt("POLYGON((170 0,-170 0,-170 10,170 10,170 0),(-175 2,-175 4,-173 4,-175 2))"),
+            "MULTIPOLYGON(((170 0,180 0,180 10,170 10,170 0)),((-180 0,-170 0,-170 10,-180 10,-180 0),(-175 2,-175 4,-173 4,-175 2)))"
+        );
+        assert_eq!(
+            split_wkt("POLYGON((0 0,10 0,10 10,0 0))"),
+            "POLYGON((0 0,10 0,10 10,0 0))"
+        );
+        // Around the north pole
+        let polar = "POLYGON((0 80,90 80,180 80,-90 80,0 80))";
+        assert_eq!(split_wkt(polar), polar);
+    }
+
+    #[test]
+    #[cfg(feature = "with-geojson")]
+    fn interpolate_z() {
+        use crate::geojson::{GeoJson, GeoJsonWriter};
+        use crate::{CoordDimensions, GeozeroGeometry};
+
+        let mut out: Vec<u8> = Vec::new();
+        let writer = GeoJsonWriter::with_dims(&mut out, CoordDimensions::xyz());
+        let mut processor = AntimeridianSplit::new(writer);
+        GeoJson(r#"{"type": "LineString", "coordinates": [[170, 0, 0], [-170, 10, 100]]}"#)
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            r#"{"type": "MultiLineString", "coordinates": [[[170,0,0],[180,5,50]],[[-180,5,50],[-170,10,100]]]}"#
+        );
+    }
+}
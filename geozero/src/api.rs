@@ -7,9 +7,9 @@
 use crate::error::{GeozeroError, Result};
 use crate::feature_processor::FeatureProcessor;
 use crate::property_processor::{
-    PropertyProcessor, PropertyReadType, PropertyReader, PropertyReaderIdx,
+    OwnedColumnValue, PropertyProcessor, PropertyReadType, PropertyReader, PropertyReaderIdx,
 };
-use crate::{CoordDimensions, GeomProcessor};
+use crate::{Bbox, BoundsProcessor, CoordDimensions, GeomProcessor};
 use std::collections::HashMap;
 
 /// Geometry processing trait.
@@ -26,6 +26,15 @@ pub trait GeozeroGeometry {
     fn srid(&self) -> Option<i32> {
         None
     }
+    /// 2D bounding box of geometry, `None` if it has no coordinates
+    fn bbox(&self) -> Result<Option<Bbox>>
+    where
+        Self: Sized,
+    {
+        let mut bounds = BoundsProcessor::new();
+        self.process_geom(&mut bounds)?;
+        Ok(bounds.bbox())
+    }
 }
 
 /// Datasource feature consumer trait.
@@ -39,6 +48,24 @@ pub trait GeozeroDatasource {
     }
 }
 
+/// Asynchronous datasource feature consumer trait.
+///
+/// Counterpart of [`GeozeroDatasource`] for datasources reading from a `tokio::io::AsyncRead`.
+/// Input is read without blocking the runtime. Line based formats like CSV and GeoJSON Lines
+/// process features as they become available, single document formats like WKT and GPX read
+/// the whole input first.
+#[cfg(feature = "with-tokio")]
+#[async_trait::async_trait]
+pub trait AsyncGeozeroDatasource {
+    /// Consume and process all selected features.
+    async fn process<P: FeatureProcessor + Send>(&mut self, processor: &mut P) -> Result<()>;
+    /// Consume and process geometries of all selected features.
+    async fn process_geom<P: GeomProcessor + Send>(&mut self, processor: &mut P) -> Result<()> {
+        let mut geom_processor = DatasourceGeomProcessor(processor);
+        self.process(&mut geom_processor).await
+    }
+}
+
 /// Feature processing API
 pub trait FeatureAccess: FeatureProperties + GeozeroGeometry {
     /// Process feature geometries and properties.
@@ -91,6 +118,14 @@ pub trait FeatureProperties {
         let _ = self.process_properties(&mut properties)?;
         Ok(properties)
     }
+    /// Return all properties with their typed values in source order
+    ///
+    /// Use `process_properties` for zero-copy access
+    fn properties_typed(&self) -> Result<Vec<(String, OwnedColumnValue)>> {
+        let mut properties = Vec::new();
+        let _ = self.process_properties(&mut properties)?;
+        Ok(properties)
+    }
 }
 
 // Newtype for GeomProcessor impl for adding no-op PropertyProcessor/FeatureProcessor impl
@@ -98,6 +133,9 @@ struct DatasourceGeomProcessor<'a, P: GeomProcessor>(&'a mut P);
 
 // Delegate GeomProcessor impl to wrapped GeomProcessor
 impl<P: GeomProcessor> GeomProcessor for DatasourceGeomProcessor<'_, P> {
+    fn supports_curves(&self) -> bool {
+        self.0.supports_curves()
+    }
     fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
         self.0.xy(x, y, idx)
     }
@@ -0,0 +1,413 @@
+use crate::error::Result;
+use crate::filter::{FeaturePredicate, FilterProcessor};
+use crate::recorded::RecordedFeature;
+use crate::{CoordDimensions, FeatureProcessor, GeomProcessor, GeozeroGeometry, PropertyProcessor};
+
+/// 2D bounding box
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bbox {
+    pub minx: f64,
+    pub miny: f64,
+    pub maxx: f64,
+    pub maxy: f64,
+}
+
+impl Bbox {
+    pub fn new(minx: f64, miny: f64, maxx: f64, maxy: f64) -> Self {
+        Bbox {
+            minx,
+            miny,
+            maxx,
+            maxy,
+        }
+    }
+
+    /// Bounding box of a single point
+    pub fn from_point(x: f64, y: f64) -> Self {
+        Bbox::new(x, y, x, y)
+    }
+
+    /// Extend to include point `(x, y)`
+    pub fn expand_xy(&mut self, x: f64, y: f64) {
+        self.minx = self.minx.min(x);
+        self.miny = self.miny.min(y);
+        self.maxx = self.maxx.max(x);
+        self.maxy = self.maxy.max(y);
+    }
+
+    /// Extend to include `other`
+    pub fn expand(&mut self, other: &Bbox) {
+        self.expand_xy(other.minx, other.miny);
+        self.expand_xy(other.maxx, other.maxy);
+    }
+
+    /// Boxes have at least one common point
+    pub fn intersects(&self, other: &Bbox) -> bool {
+        self.minx <= other.maxx
+            && other.minx <= self.maxx
+            && self.miny <= other.maxy
+            && other.miny <= self.maxy
+    }
+
+    /// `other` lies completely within this box
+    pub fn contains(&self, other: &Bbox) -> bool {
+        self.minx <= other.minx
+            && other.maxx <= self.maxx
+            && self.miny <= other.miny
+            && other.maxy <= self.maxy
+    }
+}
+
+/// 2D bounding box with optional Z range
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bounds {
+    pub bbox: Bbox,
+    /// Z range `(minz, maxz)` of coordinates with Z values
+    pub z: Option<(f64, f64)>,
+}
+
+impl Bounds {
+    fn from_coord(x: f64, y: f64, z: Option<f64>) -> Self {
+        Bounds {
+            bbox: Bbox::from_point(x, y),
+            z: z.map(|z| (z, z)),
+        }
+    }
+
+    fn expand_coord(&mut self, x: f64, y: f64, z: Option<f64>) {
+        self.bbox.expand_xy(x, y);
+        if let Some(z) = z {
+            self.z = Some(match self.z {
+                Some((minz, maxz)) => (minz.min(z), maxz.max(z)),
+                None => (z, z),
+            });
+        }
+    }
+
+    /// Extend to include `other`
+    pub fn expand(&mut self, other: &Bounds) {
+        self.bbox.expand(&other.bbox);
+        if let Some((minz, maxz)) = other.z {
+            self.z = Some(match self.z {
+                Some((z0, z1)) => (z0.min(minz), z1.max(maxz)),
+                None => (minz, maxz),
+            });
+        }
+    }
+
+    /// Bounds include a Z range
+    pub fn is_3d(&self) -> bool {
+        self.z.is_some()
+    }
+}
+
+pub(crate) fn expand_bounds(bounds: &mut Option<Bounds>, x: f64, y: f64, z: Option<f64>) {
+    match bounds {
+        Some(bounds) => bounds.expand_coord(x, y, z),
+        None => *bounds = Some(Bounds::from_coord(x, y, z)),
+    }
+}
+
+/// Processor computing the bounds of each feature and of the whole dataset
+///
+/// Bounds of features without coordinates are `None`.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::geojson::GeoJsonLineReader;
+/// use geozero::{Bbox, BoundsProcessor, GeozeroDatasource};
+///
+/// let input = r#"{"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+/// {"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": [20, 5]}}"#;
+///
+/// let mut bounds = BoundsProcessor::new();
+/// GeoJsonLineReader::new(input.as_bytes()).process(&mut bounds).unwrap();
+/// assert_eq!(bounds.bbox(), Some(Bbox::new(1.0, 1.0, 20.0, 5.0)));
+/// assert_eq!(bounds.feature_bounds().len(), 2);
+/// # }
+/// ```
+#[derive(Default, Debug)]
+pub struct BoundsProcessor {
+    dataset: Option<Bounds>,
+    feature: Option<Bounds>,
+    features: Vec<Option<Bounds>>,
+}
+
+impl BoundsProcessor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bounds of all processed coordinates
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.dataset
+    }
+
+    /// 2D bounding box of all processed coordinates
+    pub fn bbox(&self) -> Option<Bbox> {
+        self.dataset.map(|bounds| bounds.bbox)
+    }
+
+    /// Bounds of each processed feature, in processing order
+    pub fn feature_bounds(&self) -> &[Option<Bounds>] {
+        &self.features
+    }
+
+    fn add_coord(&mut self, x: f64, y: f64, z: Option<f64>) {
+        expand_bounds(&mut self.feature, x, y, z);
+        expand_bounds(&mut self.dataset, x, y, z);
+    }
+}
+
+impl GeomProcessor for BoundsProcessor {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyz()
+    }
+    fn multi_dim(&self) -> bool {
+        true
+    }
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.add_coord(x, y, None);
+        Ok(())
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        _m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        self.add_coord(x, y, z);
+        Ok(())
+    }
+    fn empty_point(&mut self, _idx: usize) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl PropertyProcessor for BoundsProcessor {}
+
+impl FeatureProcessor for BoundsProcessor {
+    fn dataset_begin(&mut self, _name: Option<&str>) -> Result<()> {
+        *self = Self::default();
+        Ok(())
+    }
+    fn feature_begin(&mut self, _idx: u64) -> Result<()> {
+        self.feature = None;
+        Ok(())
+    }
+    fn feature_end(&mut self, _idx: u64) -> Result<()> {
+        self.features.push(self.feature.take());
+        Ok(())
+    }
+}
+
+/// Spatial relation of a feature envelope to the extent of a [`BboxFilter`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BboxRelation {
+    /// Envelope intersects the extent
+    Intersects,
+    /// Envelope lies completely within the extent
+    Contained,
+}
+
+/// Feature predicate comparing the feature envelope with an extent
+///
+/// Features without geometry or with empty geometries don't match.
+#[derive(Clone, Debug)]
+pub struct BboxPredicate {
+    pub extent: Bbox,
+    pub relation: BboxRelation,
+}
+
+impl FeaturePredicate for BboxPredicate {
+    fn matches(&mut self, feature: &RecordedFeature) -> bool {
+        let Ok(Some(envelope)) = feature.bbox() else {
+            return false;
+        };
+        match self.relation {
+            BboxRelation::Intersects => self.extent.intersects(&envelope),
+            BboxRelation::Contained => self.extent.contains(&envelope),
+        }
+    }
+}
+
+/// Processor forwarding only features whose envelope intersects or is contained in an extent
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::geojson::{GeoJsonLineReader, GeoJsonWriter};
+/// use geozero::{Bbox, BboxFilter, GeozeroDatasource};
+///
+/// let input = r#"{"type": "Feature", "properties": {"name": "a"}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+/// {"type": "Feature", "properties": {"name": "b"}, "geometry": {"type": "Point", "coordinates": [20, 20]}}"#;
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut filter = BboxFilter::intersecting(GeoJsonWriter::new(&mut out), Bbox::new(0.0, 0.0, 10.0, 10.0));
+/// GeoJsonLineReader::new(input.as_bytes()).process(&mut filter).unwrap();
+/// # }
+/// ```
+pub type BboxFilter<P> = FilterProcessor<P, BboxPredicate>;
+
+impl<P: FeatureProcessor> BboxFilter<P> {
+    /// Keep features whose envelope intersects `extent`
+    pub fn intersecting(inner: P, extent: Bbox) -> Self {
+        FilterProcessor::new(
+            inner,
+            BboxPredicate {
+                extent,
+                relation: BboxRelation::Intersects,
+            },
+        )
+    }
+
+    /// Keep features whose envelope is contained in `extent`
+    pub fn contained(inner: P, extent: Bbox) -> Self {
+        FilterProcessor::new(
+            inner,
+            BboxPredicate {
+                extent,
+                relation: BboxRelation::Contained,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RecordedGeometry;
+
+    fn feature(coords: &[(f64, f64)]) -> RecordedFeature {
+        let mut geometry = RecordedGeometry::default();
+        geometry.linestring_begin(true, coords.len(), 0).unwrap();
+        for (idx, (x, y)) in coords.iter().enumerate() {
+            geometry.xy(*x, *y, idx).unwrap();
+        }
+        geometry.linestring_end(true, 0).unwrap();
+        RecordedFeature {
+            geometry: Some(geometry),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn bbox_relations() {
+        let extent = Bbox::new(0.0, 0.0, 10.0, 10.0);
+        assert!(extent.intersects(&Bbox::new(5.0, 5.0, 15.0, 15.0)));
+        assert!(extent.intersects(&Bbox::new(10.0, 10.0, 15.0, 15.0)));
+        assert!(!extent.intersects(&Bbox::new(11.0, 0.0, 15.0, 15.0)));
+        assert!(extent.contains(&Bbox::new(1.0, 1.0, 9.0, 9.0)));
+        assert!(!extent.contains(&Bbox::new(1.0, 1.0, 11.0, 9.0)));
+    }
+
+    #[test]
+    fn bounds() {
+        let mut bounds = BoundsProcessor::new();
+        bounds.dataset_begin(None).unwrap();
+        feature(&[(1.0, 5.0), (3.0, 2.0)])
+            .process(&mut bounds)
+            .unwrap();
+        RecordedFeature::default().process(&mut bounds).unwrap();
+        let mut geometry = RecordedGeometry::default();
+        geometry.point_begin(0).unwrap();
+        geometry
+            .coordinate(-1.0, 4.0, Some(10.0), None, None, None, 0)
+            .unwrap();
+        geometry.point_end(0).unwrap();
+        RecordedFeature {
+            geometry: Some(geometry),
+            ..Default::default()
+        }
+        .process(&mut bounds)
+        .unwrap();
+        bounds.dataset_end().unwrap();
+
+        assert_eq!(
+            bounds.feature_bounds(),
+            [
+                Some(Bounds {
+                    bbox: Bbox::new(1.0, 2.0, 3.0, 5.0),
+                    z: None
+                }),
+                None,
+                Some(Bounds {
+                    bbox: Bbox::new(-1.0, 4.0, -1.0, 4.0),
+                    z: Some((10.0, 10.0))
+                }),
+            ]
+        );
+        let dataset = bounds.bounds().unwrap();
+        assert_eq!(dataset.bbox, Bbox::new(-1.0, 2.0, 3.0, 5.0));
+        assert_eq!(dataset.z, Some((10.0, 10.0)));
+        assert!(dataset.is_3d());
+    }
+
+    #[test]
+    fn geometry_bbox() {
+        let geom = feature(&[(1.0, 5.0), (3.0, 2.0)]).geometry.unwrap();
+        assert_eq!(geom.bbox().unwrap(), Some(Bbox::new(1.0, 2.0, 3.0, 5.0)));
+        assert_eq!(RecordedGeometry::default().bbox().unwrap(), None);
+    }
+
+    #[test]
+    fn predicate() {
+        let mut intersects = BboxPredicate {
+            extent: Bbox::new(0.0, 0.0, 10.0, 10.0),
+            relation: BboxRelation::Intersects,
+        };
+        let mut contained = BboxPredicate {
+            relation: BboxRelation::Contained,
+            ..intersects.clone()
+        };
+        let inside = feature(&[(1.0, 1.0), (2.0, 2.0)]);
+        let crossing = feature(&[(5.0, 5.0), (20.0, 20.0)]);
+        let outside = feature(&[(15.0, 15.0), (20.0, 20.0)]);
+        assert!(intersects.matches(&inside));
+        assert!(intersects.matches(&crossing));
+        assert!(!intersects.matches(&outside));
+        assert!(contained.matches(&inside));
+        assert!(!contained.matches(&crossing));
+        assert!(!contained.matches(&outside));
+        assert!(!intersects.matches(&RecordedFeature::default()));
+    }
+
+    #[test]
+    fn bbox_filter() {
+        struct IdxCollector(Vec<u64>);
+        impl FeatureProcessor for IdxCollector {
+            fn feature_begin(&mut self, idx: u64) -> Result<()> {
+                self.0.push(idx);
+                Ok(())
+            }
+        }
+        impl GeomProcessor for IdxCollector {}
+        impl PropertyProcessor for IdxCollector {}
+
+        let mut filter =
+            BboxFilter::contained(IdxCollector(Vec::new()), Bbox::new(0.0, 0.0, 10.0, 10.0));
+        filter.dataset_begin(None).unwrap();
+        for (idx, coords) in [[(11.0, 1.0)], [(1.0, 1.0)], [(2.0, 2.0)]]
+            .iter()
+            .enumerate()
+        {
+            RecordedFeature {
+                idx: idx as u64,
+                ..feature(coords)
+            }
+            .process(&mut filter)
+            .unwrap();
+        }
+        filter.dataset_end().unwrap();
+        assert_eq!(filter.into_inner().0, [0, 1]);
+    }
+}
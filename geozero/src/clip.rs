@@ -0,0 +1,742 @@
+use crate::coord::Coord;
+use crate::error::{GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::orient::Winding;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use crate::recorded::FeatureRecorder;
+use crate::Bbox;
+
+/// Buffered geometry
+#[derive(Debug)]
+pub(crate) enum Geom {
+    Point(Option<Coord>),
+    MultiPoint(Vec<Coord>),
+    LineString(Vec<Coord>),
+    MultiLineString(Vec<Vec<Coord>>),
+    Polygon(Vec<Vec<Coord>>),
+    MultiPolygon(Vec<Vec<Vec<Coord>>>),
+    GeometryCollection(Vec<Geom>),
+}
+
+fn contains(bbox: &Bbox, c: &Coord) -> bool {
+    bbox.minx <= c.x && c.x <= bbox.maxx && bbox.miny <= c.y && c.y <= bbox.maxy
+}
+
+/// Parameter range `(t0, t1)` of segment `a`-`b` within `bbox` (Liang-Barsky)
+fn clip_segment(a: &Coord, b: &Coord, bbox: &Bbox) -> Option<(f64, f64)> {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let (mut t0, mut t1) = (0.0, 1.0);
+    for (p, q) in [
+        (-dx, a.x - bbox.minx),
+        (dx, bbox.maxx - a.x),
+        (-dy, a.y - bbox.miny),
+        (dy, bbox.maxy - a.y),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let r = q / p;
+            if p < 0.0 {
+                if r > t1 {
+                    return None;
+                }
+                t0 = r.max(t0);
+            } else {
+                if r < t0 {
+                    return None;
+                }
+                t1 = r.min(t1);
+            }
+        }
+    }
+    Some((t0, t1))
+}
+
+/// Parts of a linestring within `bbox`
+fn clip_line(coords: &[Coord], bbox: &Bbox) -> Vec<Vec<Coord>> {
+    fn finish(parts: &mut Vec<Vec<Coord>>, part: &mut Vec<Coord>) {
+        let part = std::mem::take(part);
+        // Skip parts only touching the boundary
+        if part.iter().any(|c| c.x != part[0].x || c.y != part[0].y) {
+            parts.push(part);
+        }
+    }
+
+    let mut parts = Vec::new();
+    let mut part = Vec::new();
+    for segment in coords.windows(2) {
+        let (a, b) = (&segment[0], &segment[1]);
+        let Some((t0, t1)) = clip_segment(a, b, bbox) else {
+            finish(&mut parts, &mut part);
+            continue;
+        };
+        if t0 > 0.0 {
+            finish(&mut parts, &mut part);
+            part.push(a.interpolate(b, t0));
+        } else if part.is_empty() {
+            part.push(*a);
+        }
+        if t1 < 1.0 {
+            part.push(a.interpolate(b, t1));
+            finish(&mut parts, &mut part);
+        } else {
+            part.push(*b);
+        }
+    }
+    finish(&mut parts, &mut part);
+    parts
+}
+
+/// Clip ring points against one rectangle edge (Sutherland-Hodgman)
+fn clip_edge(
+    points: &[Coord],
+    inside: impl Fn(&Coord) -> bool,
+    intersect: impl Fn(&Coord, &Coord) -> Coord,
+) -> Vec<Coord> {
+    let mut clipped = Vec::with_capacity(points.len() + 2);
+    let Some(mut prev) = points.last() else {
+        return clipped;
+    };
+    for cur in points {
+        match (inside(prev), inside(cur)) {
+            (true, true) => clipped.push(*cur),
+            (true, false) => clipped.push(intersect(prev, cur)),
+            (false, true) => {
+                clipped.push(intersect(prev, cur));
+                clipped.push(*cur);
+            }
+            (false, false) => {}
+        }
+        prev = cur;
+    }
+    clipped
+}
+
+fn at_x(a: &Coord, b: &Coord, x: f64) -> Coord {
+    Coord {
+        x,
+        ..a.interpolate(b, (x - a.x) / (b.x - a.x))
+    }
+}
+
+fn at_y(a: &Coord, b: &Coord, y: f64) -> Coord {
+    Coord {
+        y,
+        ..a.interpolate(b, (y - a.y) / (b.y - a.y))
+    }
+}
+
+/// Closed ring clipped to `bbox`, `None` if no area is left
+fn clip_ring(ring: &[Coord], bbox: &Bbox) -> Option<Vec<Coord>> {
+    let mut points = ring.to_vec();
+    if let (Some(first), Some(last)) = (ring.first(), ring.last()) {
+        if ring.len() > 1 && first.x == last.x && first.y == last.y {
+            points.pop();
+        }
+    }
+    let points = clip_edge(&points, |c| c.x >= bbox.minx, |a, b| at_x(a, b, bbox.minx));
+    let points = clip_edge(&points, |c| c.x <= bbox.maxx, |a, b| at_x(a, b, bbox.maxx));
+    let points = clip_edge(&points, |c| c.y >= bbox.miny, |a, b| at_y(a, b, bbox.miny));
+    let mut points = clip_edge(&points, |c| c.y <= bbox.maxy, |a, b| at_y(a, b, bbox.maxy));
+    Winding::of_ring(points.iter().map(|c| (c.x, c.y)))?;
+    points.push(points[0]);
+    Some(points)
+}
+
+/// Polygon clipped to `bbox`, `None` if the exterior ring has no area left
+pub(crate) fn clip_polygon(rings: &[Vec<Coord>], bbox: &Bbox) -> Option<Vec<Vec<Coord>>> {
+    let mut rings = rings.iter();
+    let exterior = clip_ring(rings.next()?, bbox)?;
+    Some(
+        std::iter::once(exterior)
+            .chain(rings.filter_map(|ring| clip_ring(ring, bbox)))
+            .collect(),
+    )
+}
+
+fn non_empty<T>(items: Vec<T>) -> Option<Vec<T>> {
+    (!items.is_empty()).then_some(items)
+}
+
+impl Geom {
+    /// Geometry clipped to `bbox`, `None` if nothing is left
+    fn clip(self, bbox: &Bbox) -> Option<Geom> {
+        match self {
+            Geom::Point(coord) => coord
+                .filter(|c| contains(bbox, c))
+                .map(|c| Geom::Point(Some(c))),
+            Geom::MultiPoint(mut points) => {
+                points.retain(|c| contains(bbox, c));
+                non_empty(points).map(Geom::MultiPoint)
+            }
+            Geom::LineString(coords) => {
+                let mut parts = clip_line(&coords, bbox);
+                match parts.len() {
+                    0 => None,
+                    1 => parts.pop().map(Geom::LineString),
+                    _ => Some(Geom::MultiLineString(parts)),
+                }
+            }
+            Geom::MultiLineString(lines) => {
+                let parts = lines.iter().flat_map(|line| clip_line(line, bbox));
+                non_empty(parts.collect()).map(Geom::MultiLineString)
+            }
+            Geom::Polygon(rings) => clip_polygon(&rings, bbox).map(Geom::Polygon),
+            Geom::MultiPolygon(polygons) => {
+                let polygons = polygons.iter().filter_map(|p| clip_polygon(p, bbox));
+                non_empty(polygons.collect()).map(Geom::MultiPolygon)
+            }
+            Geom::GeometryCollection(members) => {
+                let members = members.into_iter().filter_map(|g| g.clip(bbox));
+                non_empty(members.collect()).map(Geom::GeometryCollection)
+            }
+        }
+    }
+
+    pub fn process(&self, out: &mut dyn GeomProcessor, multi_dim: bool, idx: usize) -> Result<()> {
+        let coord = |out: &mut dyn GeomProcessor, c: &Coord, idx: usize| {
+            if multi_dim {
+                out.coordinate(c.x, c.y, c.z, c.m, c.t, c.tm, idx)
+            } else {
+                out.xy(c.x, c.y, idx)
+            }
+        };
+        let line = |out: &mut dyn GeomProcessor, coords: &[Coord], tagged: bool, idx: usize| {
+            out.linestring_begin(tagged, coords.len(), idx)?;
+            for (i, c) in coords.iter().enumerate() {
+                coord(out, c, i)?;
+            }
+            out.linestring_end(tagged, idx)
+        };
+        let polygon = |out: &mut dyn GeomProcessor, rings: &[Vec<Coord>], tagged, idx| {
+            out.polygon_begin(tagged, rings.len(), idx)?;
+            for (i, ring) in rings.iter().enumerate() {
+                line(out, ring, false, i)?;
+            }
+            out.polygon_end(tagged, idx)
+        };
+        match self {
+            Geom::Point(Some(c)) => {
+                out.point_begin(idx)?;
+                coord(out, c, 0)?;
+                out.point_end(idx)
+            }
+            Geom::Point(None) => out.empty_point(idx),
+            Geom::MultiPoint(points) => {
+                out.multipoint_begin(points.len(), idx)?;
+                for (i, c) in points.iter().enumerate() {
+                    coord(out, c, i)?;
+                }
+                out.multipoint_end(idx)
+            }
+            Geom::LineString(coords) => line(out, coords, true, idx),
+            Geom::MultiLineString(lines) => {
+                out.multilinestring_begin(lines.len(), idx)?;
+                for (i, coords) in lines.iter().enumerate() {
+                    line(out, coords, false, i)?;
+                }
+                out.multilinestring_end(idx)
+            }
+            Geom::Polygon(rings) => polygon(out, rings, true, idx),
+            Geom::MultiPolygon(polygons) => {
+                out.multipolygon_begin(polygons.len(), idx)?;
+                for (i, rings) in polygons.iter().enumerate() {
+                    polygon(out, rings, false, i)?;
+                }
+                out.multipolygon_end(idx)
+            }
+            Geom::GeometryCollection(members) => {
+                out.geometrycollection_begin(members.len(), idx)?;
+                for (i, member) in members.iter().enumerate() {
+                    member.process(out, multi_dim, i)?;
+                }
+                out.geometrycollection_end(idx)
+            }
+        }
+    }
+}
+
+/// Geometry buffered from processor events
+#[derive(Debug, Default)]
+pub(crate) struct GeomBuilder {
+    /// Open geometries
+    stack: Vec<Geom>,
+    /// Coordinates of the current linestring
+    line: Option<Vec<Coord>>,
+}
+
+impl GeomBuilder {
+    pub fn coord(&mut self, coord: Coord) -> Result<()> {
+        if let Some(line) = &mut self.line {
+            line.push(coord);
+            return Ok(());
+        }
+        match self.stack.last_mut() {
+            Some(Geom::Point(point)) => *point = Some(coord),
+            Some(Geom::MultiPoint(points)) => points.push(coord),
+            _ => {
+                return Err(GeozeroError::Geometry(
+                    "coordinate outside of point or linestring".to_string(),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    pub fn begin(&mut self, geom: Geom) {
+        self.stack.push(geom);
+    }
+
+    pub fn line_begin(&mut self, size: usize) {
+        self.line = Some(Vec::with_capacity(size));
+    }
+
+    /// Complete the current linestring, returns the top level geometry once completed
+    pub fn line_end(&mut self) -> Result<Option<Geom>> {
+        let coords = self.line.take().unwrap_or_default();
+        self.add(Geom::LineString(coords))
+    }
+
+    /// Complete the innermost open geometry, returns the top level geometry once completed
+    pub fn end(&mut self) -> Result<Option<Geom>> {
+        match self.stack.pop() {
+            Some(geom) => self.add(geom),
+            None => Err(GeozeroError::Geometry(
+                "geometry end without begin".to_string(),
+            )),
+        }
+    }
+
+    /// Add a completed geometry to its parent, returns top level geometries
+    pub fn add(&mut self, geom: Geom) -> Result<Option<Geom>> {
+        match (self.stack.last_mut(), geom) {
+            (None, geom) => return Ok(Some(geom)),
+            (Some(Geom::GeometryCollection(members)), geom) => members.push(geom),
+            (Some(Geom::MultiPoint(points)), Geom::Point(point)) => points.extend(point),
+            (Some(Geom::MultiLineString(lines)), Geom::LineString(coords)) => lines.push(coords),
+            (Some(Geom::Polygon(rings)), Geom::LineString(coords)) => rings.push(coords),
+            (Some(Geom::MultiPolygon(polygons)), Geom::Polygon(rings)) => polygons.push(rings),
+            _ => {
+                return Err(GeozeroError::Geometry(
+                    "unexpected geometry nesting".to_string(),
+                ))
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Processor clipping geometries to a rectangle
+///
+/// Each geometry is buffered and clipped before being passed on. Linestrings are split into
+/// multilinestrings where they leave the rectangle, polygon rings are clipped one by one.
+/// Geometries without any part left are dropped, as are features whose geometry is clipped
+/// away completely. Feature indices stay consecutive.
+///
+/// Curves are linearized by readers, other curve, triangle and surface events are rejected.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::{Bbox, ClipProcessor, GeozeroGeometry};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = ClipProcessor::new(WktWriter::new(&mut out), Bbox::new(0.0, 0.0, 10.0, 10.0));
+/// Wkt("LINESTRING(-5 5,5 5,5 15,8 15,8 5)").process_geom(&mut processor).unwrap();
+/// assert_eq!(
+///     std::str::from_utf8(&out).unwrap(),
+///     "MULTILINESTRING((0 5,5 5,5 10),(8 10,8 5))"
+/// );
+/// # }
+/// ```
+pub struct ClipProcessor<P> {
+    inner: P,
+    bbox: Bbox,
+    buffer: f64,
+    geom: GeomBuilder,
+    /// Events of the current feature
+    recorder: Option<FeatureRecorder>,
+    /// A geometry of the current feature was kept
+    kept: bool,
+    next_idx: u64,
+}
+
+impl<P: GeomProcessor> ClipProcessor<P> {
+    pub fn new(inner: P, bbox: Bbox) -> Self {
+        ClipProcessor {
+            inner,
+            bbox,
+            buffer: 0.0,
+            geom: GeomBuilder::default(),
+            recorder: None,
+            kept: false,
+            next_idx: 0,
+        }
+    }
+
+    /// Extend the clip rectangle by `buffer` on each side
+    pub fn with_buffer(mut self, buffer: f64) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn clip_bbox(&self) -> Bbox {
+        Bbox::new(
+            self.bbox.minx - self.buffer,
+            self.bbox.miny - self.buffer,
+            self.bbox.maxx + self.buffer,
+            self.bbox.maxy + self.buffer,
+        )
+    }
+
+    /// Events of the current feature are recorded, other events are forwarded
+    fn target(&mut self) -> &mut dyn GeomProcessor {
+        match self.recorder {
+            Some(ref mut recorder) => recorder,
+            None => &mut self.inner,
+        }
+    }
+
+    /// Clip and emit a completed top level geometry
+    fn emit(&mut self, geom: Option<Geom>, idx: usize) -> Result<()> {
+        if let Some(clipped) = geom.and_then(|geom| geom.clip(&self.clip_bbox())) {
+            self.kept = true;
+            let multi_dim = self.inner.multi_dim();
+            clipped.process(self.target(), multi_dim, idx)?;
+        }
+        Ok(())
+    }
+
+    fn unsupported(&self, geometry_type: &str) -> Result<()> {
+        Err(GeozeroError::Geometry(format!(
+            "clipping {geometry_type} is not supported"
+        )))
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for ClipProcessor<P> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.next_idx = 0;
+        self.inner.dataset_begin(name)
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        let mut recorder = FeatureRecorder::default();
+        recorder.feature_begin(idx)?;
+        self.recorder = Some(recorder);
+        self.kept = false;
+        Ok(())
+    }
+    fn feature_end(&mut self, _idx: u64) -> Result<()> {
+        let Some(mut feature) = self
+            .recorder
+            .take()
+            .and_then(|recorder| recorder.into_features().pop())
+        else {
+            return Err(GeozeroError::Feature(
+                "feature_end without feature_begin".to_string(),
+            ));
+        };
+        if feature.geometry.is_none() || self.kept {
+            feature.idx = self.next_idx;
+            self.next_idx += 1;
+            feature.process(&mut self.inner)?;
+        }
+        Ok(())
+    }
+    fn geometry_begin(&mut self) -> Result<()> {
+        match self.recorder {
+            Some(ref mut recorder) => recorder.geometry_begin(),
+            None => self.inner.geometry_begin(),
+        }
+    }
+    fn geometry_end(&mut self) -> Result<()> {
+        match self.recorder {
+            Some(ref mut recorder) => recorder.geometry_end(),
+            None => self.inner.geometry_end(),
+        }
+    }
+    forward!(inner => schema, dataset_end);
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for ClipProcessor<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        match self.recorder {
+            Some(ref mut recorder) => recorder.property(idx, name, value),
+            None => self.inner.property(idx, name, value),
+        }
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for ClipProcessor<P> {
+    fn supports_curves(&self) -> bool {
+        false
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.target().srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.geom.coord(Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+            t: None,
+            tm: None,
+        })
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        self.geom.coord(Coord { x, y, z, m, t, tm })
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.add(Geom::Point(None))?;
+        self.emit(geom, idx)
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.geom.begin(Geom::Point(None));
+        Ok(())
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom.begin(Geom::MultiPoint(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.geom.line_begin(size);
+        Ok(())
+    }
+    fn linestring_end(&mut self, _tagged: bool, idx: usize) -> Result<()> {
+        let geom = self.geom.line_end()?;
+        self.emit(geom, idx)
+    }
+    fn multilinestring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom
+            .begin(Geom::MultiLineString(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn polygon_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.geom.begin(Geom::Polygon(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn polygon_end(&mut self, _tagged: bool, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom
+            .begin(Geom::MultiPolygon(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom
+            .begin(Geom::GeometryCollection(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn circularstring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("circularstring")
+    }
+    fn compoundcurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("compoundcurve")
+    }
+    fn curvepolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("curvepolygon")
+    }
+    fn multicurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("multicurve")
+    }
+    fn multisurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("multisurface")
+    }
+    fn triangle_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("triangle")
+    }
+    fn polyhedralsurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("polyhedralsurface")
+    }
+    fn tin_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("tin")
+    }
+    forward!(inner => dimensions, multi_dim);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn coords(points: &[(f64, f64)]) -> Vec<Coord> {
+        points
+            .iter()
+            .map(|&(x, y)| Coord {
+                x,
+                y,
+                z: None,
+                m: None,
+                t: None,
+                tm: None,
+            })
+            .collect()
+    }
+
+    fn xy(coords: &[Coord]) -> Vec<(f64, f64)> {
+        coords.iter().map(|c| (c.x, c.y)).collect()
+    }
+
+    #[test]
+    fn lines() {
+        let bbox = Bbox::new(0.0, 0.0, 10.0, 10.0);
+        let parts = clip_line(&coords(&[(-5.0, 5.0), (15.0, 5.0)]), &bbox);
+        assert_eq!(parts.len(), 1);
+        assert_eq!(xy(&parts[0]), [(0.0, 5.0), (10.0, 5.0)]);
+        // Outside, and touching a corner only
+        assert!(clip_line(&coords(&[(-5.0, 5.0), (-5.0, 15.0)]), &bbox).is_empty());
+        assert!(clip_line(&coords(&[(-5.0, 5.0), (5.0, -5.0)]), &bbox).is_empty());
+        assert!(clip_line(&coords(&[(-1.0, 1.0), (1.0, -1.0)]), &bbox).is_empty());
+
+        // Interpolated ordinates
+        let mut line = coords(&[(5.0, 5.0), (15.0, 5.0)]);
+        line[0].z = Some(0.0);
+        line[1].z = Some(10.0);
+        let parts = clip_line(&line, &bbox);
+        assert_eq!(parts[0][1].z, Some(5.0));
+    }
+
+    #[test]
+    fn rings() {
+        let bbox = Bbox::new(0.0, 0.0, 10.0, 10.0);
+        let ring = coords(&[
+            (-5.0, -5.0),
+            (5.0, -5.0),
+            (5.0, 5.0),
+            (-5.0, 5.0),
+            (-5.0, -5.0),
+        ]);
+        assert_eq!(
+            xy(&clip_ring(&ring, &bbox).unwrap()),
+            [(0.0, 0.0), (5.0, 0.0), (5.0, 5.0), (0.0, 5.0), (0.0, 0.0)]
+        );
+        // Surrounding the clip rectangle
+        let ring = coords(&[(-5.0, -5.0), (15.0, -5.0), (15.0, 15.0), (-5.0, -5.0)]);
+        let clipped = clip_ring(&ring, &bbox).unwrap();
+        assert_eq!(clipped.len(), 5);
+        assert_eq!(clipped.first(), clipped.last());
+        // Outside, and only sharing an edge
+        let ring = coords(&[(20.0, 20.0), (30.0, 20.0), (30.0, 30.0), (20.0, 20.0)]);
+        assert_eq!(clip_ring(&ring, &bbox), None);
+        let ring = coords(&[
+            (10.0, 0.0),
+            (20.0, 0.0),
+            (20.0, 10.0),
+            (10.0, 10.0),
+            (10.0, 0.0),
+        ]);
+        assert_eq!(clip_ring(&ring, &bbox), None);
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn clip_geometries() {
+        use crate::wkt::{Wkt, WktWriter};
+        use crate::GeozeroGeometry;
+
+        let clip = |wkt: &str, buffer: f64| {
+            let mut out: Vec<u8> = Vec::new();
+            let mut processor =
+                ClipProcessor::new(WktWriter::new(&mut out), Bbox::new(0.0, 0.0, 10.0, 10.0))
+                    .with_buffer(buffer);
+            Wkt(wkt).process_geom(&mut processor).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(clip("POINT(5 5)", 0.0), "POINT(5 5)");
+        assert_eq!(clip("POINT(11 5)", 0.0), "");
+        assert_eq!(clip("POINT(11 5)", 1.0), "POINT(11 5)");
+        assert_eq!(clip("MULTIPOINT(5 5,11 5,1 1)", 0.0), "MULTIPOINT(5 5,1 1)");
+        assert_eq!(
+            clip("MULTILINESTRING((-5 5,5 5),(20 20,30 30))", 0.0),
+            "MULTILINESTRING((0 5,5 5))"
+        );
+        assert_eq!(
+            clip(
+                "POLYGON((-5 -5,5 -5,5 5,-5 5,-5 -5),(-2 -2,-2 -1,-1 -1,-2 -2),(1 1,1 2,2 2,1 1))",
+                0.0
+            ),
+            "POLYGON((0 0,5 0,5 5,0 5,0 0),(1 1,1 2,2 2,1 1))"
+        );
+        assert_eq!(
+            clip(
+                "MULTIPOLYGON(((20 20,30 20,30 30,20 20)),((1 1,2 1,2 2,1 1)))",
+                0.0
+            ),
+            "MULTIPOLYGON(((1 1,2 1,2 2,1 1)))"
+        );
+        assert_eq!(
+            clip(
+                "GEOMETRYCOLLECTION(POINT(20 20),LINESTRING(5 -5,5 15),POINT(1 1))",
+                0.0
+            ),
+            "GEOMETRYCOLLECTION(LINESTRING(5 0,5 10),POINT(1 1))"
+        );
+        assert_eq!(
+            clip("GEOMETRYCOLLECTION(POINT(20 20),POINT(30 30))", 0.0),
+            ""
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-geojson")]
+    fn drop_features() {
+        use crate::geojson::{GeoJsonLineReader, GeoJsonWriter};
+        use crate::GeozeroDatasource;
+
+        let input = r#"{"type": "Feature", "properties": {"name": "a"}, "geometry": {"type": "Point", "coordinates": [20, 20]}}
+{"type": "Feature", "properties": {"name": "b"}, "geometry": {"type": "LineString", "coordinates": [[-5, 5], [5, 5]]}}"#;
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = ClipProcessor::new(
+            GeoJsonWriter::new(&mut out),
+            Bbox::new(0.0, 0.0, 10.0, 10.0),
+        );
+        GeoJsonLineReader::new(input.as_bytes())
+            .process(&mut processor)
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(!out.contains(r#""a""#));
+        assert!(out.contains(r#""coordinates": [[0,5],[5,5]]"#));
+    }
+}
@@ -0,0 +1,33 @@
+/// Buffered coordinate
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Coord {
+    pub x: f64,
+    pub y: f64,
+    pub z: Option<f64>,
+    pub m: Option<f64>,
+    pub t: Option<f64>,
+    pub tm: Option<u64>,
+}
+
+impl Coord {
+    /// Point at fraction `f` of the segment to `other`, interpolating all ordinates
+    pub fn interpolate(&self, other: &Coord, f: f64) -> Coord {
+        fn lerp(a: Option<f64>, b: Option<f64>, f: f64) -> Option<f64> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(a + (b - a) * f),
+                (a, b) => a.or(b),
+            }
+        }
+        Coord {
+            x: self.x + (other.x - self.x) * f,
+            y: self.y + (other.y - self.y) * f,
+            z: lerp(self.z, other.z, f),
+            m: lerp(self.m, other.m, f),
+            t: lerp(self.t, other.t, f),
+            tm: match (self.tm, other.tm) {
+                (Some(a), Some(b)) => Some((a as f64 + (b as f64 - a as f64) * f).round() as u64),
+                (a, b) => a.or(b),
+            },
+        }
+    }
+}
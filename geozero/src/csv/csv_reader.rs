@@ -1,5 +1,8 @@
-use crate::error::{GeozeroError, Result};
-use crate::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry};
+use crate::error::{ErrorPolicy, GeozeroError, Result, SkippedFeature};
+use crate::{
+    Column, ColumnType, ColumnValue, FeatureIter, FeatureProcessor, FeatureSource, GeomProcessor,
+    GeozeroDatasource, GeozeroGeometry, Schema,
+};
 
 use std::io::Read;
 use std::str::FromStr;
@@ -72,23 +75,112 @@ impl GeozeroGeometry for CsvString {
     }
 }
 
-pub struct CsvReader<R: Read> {
+pub struct CsvReader<R> {
     inner: R,
     geometry_column_name: String,
+    error_policy: ErrorPolicy,
+    skipped: Vec<SkippedFeature>,
 }
 
-impl<R: Read> CsvReader<R> {
+impl<R> CsvReader<R> {
     pub fn new(geometry_column_name: &str, inner: R) -> Self {
         Self {
             inner,
             geometry_column_name: geometry_column_name.to_string(),
+            error_policy: ErrorPolicy::Abort,
+            skipped: Vec::new(),
         }
     }
+
+    /// Handle invalid features according to `error_policy`
+    ///
+    /// Applies to records failing to parse when processing the reader, not to iterating with
+    /// `features()`.
+    pub fn with_error_policy(mut self, error_policy: ErrorPolicy) -> Self {
+        self.error_policy = error_policy;
+        self
+    }
+
+    /// Features skipped by the last `process` call
+    pub fn skipped(&self) -> &[SkippedFeature] {
+        &self.skipped
+    }
+}
+
+impl<R: Read> CsvReader<R> {
+    /// Iterate over owned features
+    ///
+    /// Fails if the geometry column is missing in the CSV headers.
+    pub fn features(self) -> Result<FeatureIter<CsvFeatures<R>>> {
+        let mut reader = csv::Reader::from_reader(self.inner);
+        let headers = reader.headers()?.clone();
+        let geometry_idx = headers
+            .iter()
+            .position(|f| f == self.geometry_column_name)
+            .ok_or(GeozeroError::ColumnNotFound)?;
+        Ok(FeatureIter::new(CsvFeatures {
+            reader,
+            headers,
+            geometry_idx,
+            record: csv::StringRecord::new(),
+        }))
+    }
+}
+
+/// CSV [`FeatureSource`] returned by [`CsvReader::features`]
+pub struct CsvFeatures<R> {
+    reader: csv::Reader<R>,
+    headers: csv::StringRecord,
+    geometry_idx: usize,
+    record: csv::StringRecord,
+}
+
+impl<R: Read> FeatureSource for CsvFeatures<R> {
+    fn process_next_feature<P: FeatureProcessor>(
+        &mut self,
+        idx: u64,
+        processor: &mut P,
+    ) -> Result<bool> {
+        if !self.reader.read_record(&mut self.record)? {
+            return Ok(false);
+        }
+        let geometry = parse_csv_geometry(&self.record, self.geometry_idx)?;
+        process_csv_record(
+            &self.record,
+            &self.headers,
+            self.geometry_idx,
+            geometry.as_ref(),
+            idx as usize,
+            processor,
+        )?;
+        Ok(true)
+    }
 }
 
 impl<R: Read> GeozeroDatasource for CsvReader<R> {
     fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
-        process_csv_features(&mut self.inner, processor, &self.geometry_column_name)
+        self.skipped = process_csv_features_with_policy(
+            &mut self.inner,
+            processor,
+            &self.geometry_column_name,
+            self.error_policy,
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "with-tokio")]
+#[async_trait::async_trait]
+impl<R: tokio::io::AsyncRead + Unpin + Send> crate::AsyncGeozeroDatasource for CsvReader<R> {
+    async fn process<P: FeatureProcessor + Send>(&mut self, processor: &mut P) -> Result<()> {
+        self.skipped = process_csv_features_async_with_policy(
+            &mut self.inner,
+            processor,
+            &self.geometry_column_name,
+            self.error_policy,
+        )
+        .await?;
+        Ok(())
     }
 }
 
@@ -154,6 +246,19 @@ pub fn process_csv_features(
     processor: &mut impl FeatureProcessor,
     geometry_column: &str,
 ) -> Result<()> {
+    process_csv_features_with_policy(input, processor, geometry_column, ErrorPolicy::Abort)?;
+    Ok(())
+}
+
+/// Read and process CSV features, handling invalid features according to `policy`.
+///
+/// Returns the skipped features. Processed features are numbered consecutively.
+pub fn process_csv_features_with_policy(
+    input: impl Read,
+    processor: &mut impl FeatureProcessor,
+    geometry_column: &str,
+    policy: ErrorPolicy,
+) -> Result<Vec<SkippedFeature>> {
     let mut reader = csv::Reader::from_reader(input);
     let headers = reader.headers()?.clone();
     processor.dataset_begin(None)?;
@@ -162,50 +267,260 @@ pub fn process_csv_features(
         .iter()
         .position(|f| f == geometry_column)
         .ok_or(GeozeroError::ColumnNotFound)?;
+    processor.schema(&csv_schema(&headers, geometry_idx))?;
 
-    for (feature_idx, record) in reader.into_records().enumerate() {
-        let record = record?;
-        processor.feature_begin(feature_idx as u64)?;
+    let mut skipped = Vec::new();
+    let mut feature_idx = 0;
+    for (record_idx, record) in reader.into_records().enumerate() {
+        let line = match &record {
+            Ok(record) => record.position(),
+            Err(e) => e.position(),
+        }
+        .map(|pos| pos.line());
+        let parsed = record.map_err(GeozeroError::from).and_then(|record| {
+            let geometry = parse_csv_geometry(&record, geometry_idx)?;
+            Ok((record, geometry))
+        });
+        match parsed {
+            Ok((record, geometry)) => {
+                process_csv_record(
+                    &record,
+                    &headers,
+                    geometry_idx,
+                    geometry.as_ref(),
+                    feature_idx,
+                    processor,
+                )?;
+                feature_idx += 1;
+            }
+            Err(e) => policy.skip(e, record_idx as u64, line, &mut skipped)?,
+        }
+    }
 
-        processor.properties_begin()?;
+    processor.dataset_end()?;
+    Ok(skipped)
+}
 
-        let properties_iter = headers
-            .iter()
-            .zip(record.iter())
-            .enumerate()
-            // skip the geometry field -  we process it after all the "properties"
-            .filter(|(input_idx, _)| *input_idx != geometry_idx)
-            .map(|(_input_idx, (header, value))| (header, value));
-
-        for (output_idx, (header, field)) in properties_iter.enumerate() {
-            let value = &ColumnValue::String(field);
-            processor.property(output_idx, header, value)?;
+/// Read and process CSV features from an async reader.
+#[cfg(feature = "with-tokio")]
+pub async fn process_csv_features_async(
+    input: impl tokio::io::AsyncRead + Unpin,
+    processor: &mut impl FeatureProcessor,
+    geometry_column: &str,
+) -> Result<()> {
+    process_csv_features_async_with_policy(input, processor, geometry_column, ErrorPolicy::Abort)
+        .await?;
+    Ok(())
+}
+
+/// Read and process CSV features from an async reader, handling invalid features according to `policy`.
+///
+/// Returns the skipped features. Processed features are numbered consecutively.
+#[cfg(feature = "with-tokio")]
+pub async fn process_csv_features_async_with_policy(
+    input: impl tokio::io::AsyncRead + Unpin,
+    processor: &mut impl FeatureProcessor,
+    geometry_column: &str,
+    policy: ErrorPolicy,
+) -> Result<Vec<SkippedFeature>> {
+    let mut records = AsyncCsvRecords::new(tokio::io::BufReader::new(input));
+    let headers = records.read_record().await?.unwrap_or_default();
+    processor.dataset_begin(None)?;
+
+    let geometry_idx = headers
+        .iter()
+        .position(|f| f == geometry_column)
+        .ok_or(GeozeroError::ColumnNotFound)?;
+    processor.schema(&csv_schema(&headers, geometry_idx))?;
+
+    let mut skipped = Vec::new();
+    let mut feature_idx = 0;
+    let mut record_idx = 0;
+    loop {
+        let parsed = match records.read_record().await {
+            Ok(None) => break,
+            Ok(Some(record)) => parse_csv_geometry(&record, geometry_idx).map(|g| (record, g)),
+            Err(e) => Err(e),
+        };
+        match parsed {
+            Ok((record, geometry)) => {
+                process_csv_record(
+                    &record,
+                    &headers,
+                    geometry_idx,
+                    geometry.as_ref(),
+                    feature_idx,
+                    processor,
+                )?;
+                feature_idx += 1;
+            }
+            Err(e) => policy.skip(e, record_idx, Some(records.line), &mut skipped)?,
         }
+        record_idx += 1;
+    }
 
-        processor.properties_end()?;
+    processor.dataset_end()?;
+    Ok(skipped)
+}
 
-        let geometry_field = record
-            .get(geometry_idx)
-            .ok_or(GeozeroError::ColumnNotFound)?;
+/// CSV records of an async reader, parsed with the state machine of [`csv::Reader`]
+#[cfg(feature = "with-tokio")]
+struct AsyncCsvRecords<R> {
+    input: R,
+    parser: csv_core::Reader,
+    /// Unescaped field data of the current record, reused and grown as needed
+    output: Vec<u8>,
+    /// Field end offsets of the current record, reused and grown as needed
+    ends: Vec<usize>,
+    /// Field count of the first record
+    fields: Option<usize>,
+    /// Line of the last record, starting at 1
+    line: u64,
+}
 
-        // Do all formats allow empty geometries?
-        if !geometry_field.is_empty() {
-            processor.geometry_begin()?;
-            crate::wkt::wkt_reader::read_wkt(&mut geometry_field.as_bytes(), processor).map_err(
-                |e| {
-                    // +2 to start at line 1 and to account for the header row
-                    let line = feature_idx + 2;
-                    log::warn!("line {line}: invalid WKT: '{geometry_field}', record: {record:?}");
-                    e
-                },
-            )?;
-            processor.geometry_end()?;
+#[cfg(feature = "with-tokio")]
+impl<R: tokio::io::AsyncBufRead + Unpin> AsyncCsvRecords<R> {
+    fn new(input: R) -> Self {
+        AsyncCsvRecords {
+            input,
+            parser: csv_core::Reader::new(),
+            output: vec![0; 1024],
+            ends: vec![0; 16],
+            fields: None,
+            line: 0,
+        }
+    }
+
+    /// Read the next record, which may span several lines when a quoted field contains newlines.
+    ///
+    /// Like [`csv::Reader`], records with a different field count than the first record are
+    /// rejected after being consumed.
+    async fn read_record(&mut self) -> Result<Option<csv::StringRecord>> {
+        use tokio::io::AsyncBufReadExt;
+
+        let (mut outlen, mut endlen) = (0, 0);
+        if self.fields.is_none() {
+            // Skip a UTF-8 BOM, like `csv::Reader`
+            if self.input.fill_buf().await?.starts_with(b"\xef\xbb\xbf") {
+                self.input.consume(3);
+            }
+        }
+        self.line = self.parser.line();
+        loop {
+            // An empty input signals the end of the data to the parser
+            let input = self.input.fill_buf().await?;
+            let (result, nin, nout, nend) = self.parser.read_record(
+                input,
+                &mut self.output[outlen..],
+                &mut self.ends[endlen..],
+            );
+            self.input.consume(nin);
+            outlen += nout;
+            endlen += nend;
+            match result {
+                csv_core::ReadRecordResult::InputEmpty => {}
+                csv_core::ReadRecordResult::OutputFull => {
+                    self.output.resize(self.output.len() * 2, 0)
+                }
+                csv_core::ReadRecordResult::OutputEndsFull => {
+                    self.ends.resize(self.ends.len() * 2, 0)
+                }
+                csv_core::ReadRecordResult::Record => break,
+                csv_core::ReadRecordResult::End => return Ok(None),
+            }
+        }
+        let fields = std::str::from_utf8(&self.output[..outlen])
+            .map_err(|e| GeozeroError::Dataset(format!("CSV error: line {}: {e}", self.line)))?;
+        let mut record = csv::StringRecord::with_capacity(outlen, endlen);
+        let mut start = 0;
+        for &end in &self.ends[..endlen] {
+            record.push_field(&fields[start..end]);
+            start = end;
+        }
+        let mut pos = csv::Position::new();
+        pos.set_line(self.line);
+        record.set_position(Some(pos));
+
+        let expected = *self.fields.get_or_insert(endlen);
+        if endlen != expected {
+            return Err(GeozeroError::Dataset(format!(
+                "CSV error: line {}: found record with {endlen} fields, but the previous record has {expected} fields",
+                self.line
+            )));
         }
+        Ok(Some(record))
+    }
+}
+
+/// Schema with all non-geometry columns as strings
+fn csv_schema(headers: &csv::StringRecord, geometry_idx: usize) -> Schema {
+    let columns = headers
+        .iter()
+        .enumerate()
+        .filter(|(input_idx, _)| *input_idx != geometry_idx)
+        .map(|(_, header)| Column::new(header, Some(ColumnType::String)))
+        .collect();
+    Schema::new(columns)
+}
+
+/// Parse the WKT geometry of a record, `None` if the geometry field is empty
+fn parse_csv_geometry(
+    record: &csv::StringRecord,
+    geometry_idx: usize,
+) -> Result<Option<wkt::Wkt<f64>>> {
+    let geometry_field = record
+        .get(geometry_idx)
+        .ok_or(GeozeroError::ColumnNotFound)?;
 
-        processor.feature_end(feature_idx as u64)?;
+    // Do all formats allow empty geometries?
+    if geometry_field.is_empty() {
+        return Ok(None);
     }
+    wkt::Wkt::from_str(geometry_field).map(Some).map_err(|e| {
+        let line = record.position().map_or(0, |pos| pos.line());
+        log::warn!("line {line}: invalid WKT: '{geometry_field}', record: {record:?}");
+        GeozeroError::Geometry(e.to_string())
+    })
+}
+
+fn process_csv_record(
+    record: &csv::StringRecord,
+    headers: &csv::StringRecord,
+    geometry_idx: usize,
+    geometry: Option<&wkt::Wkt<f64>>,
+    feature_idx: usize,
+    processor: &mut impl FeatureProcessor,
+) -> Result<()> {
+    processor.feature_begin(feature_idx as u64)?;
 
-    processor.dataset_end()
+    processor.properties_begin()?;
+
+    let properties_iter = headers
+        .iter()
+        .zip(record.iter())
+        .enumerate()
+        // skip the geometry field -  we process it after all the "properties"
+        .filter(|(input_idx, _)| *input_idx != geometry_idx)
+        .map(|(_input_idx, (header, value))| (header, value));
+
+    for (output_idx, (header, field)) in properties_iter.enumerate() {
+        let value = if field.is_empty() {
+            &ColumnValue::Null
+        } else {
+            &ColumnValue::String(field)
+        };
+        processor.property(output_idx, header, value)?;
+    }
+
+    processor.properties_end()?;
+
+    if let Some(wkt) = geometry {
+        processor.geometry_begin()?;
+        crate::wkt::wkt_reader::process_wkt_geom_n(&wkt.item, 0, processor)?;
+        processor.geometry_end()?;
+    }
+
+    processor.feature_end(feature_idx as u64)
 }
 
 impl From<csv::Error> for GeozeroError {
@@ -402,6 +717,68 @@ mod tests {
         assert_eq!(expected_geojson, actual_geojson);
     }
 
+    #[test]
+    fn feature_iter() {
+        use crate::{OwnedColumnValue, ToWkt};
+
+        let input = r#"address,report location,incident number
+904 7th Av,POINT (-122.329051 47.6069),F190051945
+9610 53rd Av S,POINT (-122.266529 47.515984),
+"#;
+        let features = CsvReader::new("report location", input.as_bytes())
+            .features()
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[1].idx, 1);
+        assert_eq!(
+            features[1].properties,
+            vec![
+                (
+                    "address".to_string(),
+                    OwnedColumnValue::String("9610 53rd Av S".to_string())
+                ),
+                ("incident number".to_string(), OwnedColumnValue::Null),
+            ]
+        );
+        assert_eq!(
+            features[1].to_wkt().unwrap(),
+            "POINT(-122.266529 47.515984)"
+        );
+
+        assert!(CsvReader::new("geom", input.as_bytes()).features().is_err());
+    }
+
+    #[test]
+    fn schema() {
+        use crate::PropertyProcessor;
+
+        struct SchemaReader(Option<Schema>);
+        impl FeatureProcessor for SchemaReader {
+            fn schema(&mut self, schema: &Schema) -> Result<()> {
+                self.0 = Some(schema.clone());
+                Ok(())
+            }
+        }
+        impl GeomProcessor for SchemaReader {}
+        impl PropertyProcessor for SchemaReader {}
+
+        let mut csv = Csv::new(
+            "report location",
+            "address,report location,incident number\n904 7th Av,POINT (1 2),F190051945",
+        );
+        let mut processor = SchemaReader(None);
+        csv.process(&mut processor).unwrap();
+        assert_eq!(
+            processor.0.unwrap().columns,
+            vec![
+                Column::new("address", Some(ColumnType::String)),
+                Column::new("incident number", Some(ColumnType::String)),
+            ]
+        );
+    }
+
     #[test]
     fn missing_geom() {
         use crate::geojson::conversion::ProcessToJson;
@@ -420,6 +797,78 @@ mod tests {
         serde_json::from_str::<serde_json::Value>(&json).unwrap();
     }
 
+    #[test]
+    fn error_policy() {
+        use crate::wkt::WktWriter;
+        use crate::{FeatureRecorder, ToWkt};
+
+        let input = r#"name,geom
+first,POINT(1 1)
+second,POINT(oops)
+third,POINT(3 3),extra
+fourth,POINT(4 4)
+"#;
+        let mut out: Vec<u8> = Vec::new();
+        let err = process_csv_features(input.as_bytes(), &mut WktWriter::new(&mut out), "geom");
+        assert!(matches!(err, Err(GeozeroError::Geometry(_))));
+
+        for policy in [ErrorPolicy::Skip, ErrorPolicy::Collect] {
+            let mut recorder = FeatureRecorder::new();
+            let mut reader = CsvReader::new("geom", input.as_bytes()).with_error_policy(policy);
+            reader.process(&mut recorder).unwrap();
+            let features = recorder.into_features();
+            assert_eq!(
+                features
+                    .iter()
+                    .map(|f| (f.idx, f.to_wkt().unwrap()))
+                    .collect::<Vec<_>>(),
+                vec![(0, "POINT(1 1)".to_string()), (1, "POINT(4 4)".to_string())]
+            );
+            let skipped = reader.skipped();
+            assert_eq!(
+                skipped.iter().map(|s| (s.idx, s.line)).collect::<Vec<_>>(),
+                vec![(1, Some(3)), (2, Some(4))]
+            );
+            if policy == ErrorPolicy::Collect {
+                assert!(matches!(skipped[0].error, Some(GeozeroError::Geometry(_))));
+                assert!(matches!(skipped[1].error, Some(GeozeroError::Dataset(_))));
+            } else {
+                assert!(skipped.iter().all(|s| s.error.is_none()));
+            }
+        }
+    }
+
+    #[test]
+    fn null_values() {
+        use crate::geojson::conversion::ProcessToJson;
+        use crate::ProcessToCsv;
+
+        let input = r#"address,type,report location
+904 7th Av,,POINT (-122.329051 47.6069)
+,Aid Response,POINT (-122.266529 47.515984)
+"#;
+        let mut csv = Csv::new("report location", input);
+        let actual_geojson: serde_json::Value =
+            serde_json::from_str(&csv.to_json().unwrap()).unwrap();
+        assert_eq!(
+            actual_geojson["features"][0]["properties"],
+            serde_json::json!({"address": "904 7th Av", "type": null})
+        );
+        assert_eq!(
+            actual_geojson["features"][1]["properties"],
+            serde_json::json!({"address": null, "type": "Aid Response"})
+        );
+
+        let mut csv = Csv::new("report location", input);
+        assert_eq!(
+            csv.to_csv().unwrap(),
+            r#"geometry,address,type
+POINT(-122.329051 47.6069),904 7th Av,
+POINT(-122.266529 47.515984),,Aid Response
+"#
+        );
+    }
+
     #[test]
     fn non_empty_geometry_collection() {
         use crate::ToWkt;
@@ -450,4 +899,80 @@ mod tests {
         let expected = "GEOMETRYCOLLECTION EMPTY";
         assert_eq!(expected, actual);
     }
+
+    #[cfg(feature = "with-tokio")]
+    #[tokio::test]
+    async fn async_csv_reader_feature_processor() {
+        use crate::csv::CsvWriter;
+        use crate::AsyncGeozeroDatasource;
+
+        let input = r#"address,type,report location
+"904 7th Av
+Seattle",Car Fire,POINT (-122.329051 47.6069)
+
+9610 53rd Av S,Aid Response,POINT (-122.266529 47.515984)
+"#;
+        let mut csv = CsvReader::new("report location", input.as_bytes());
+        let mut out: Vec<u8> = Vec::new();
+        AsyncGeozeroDatasource::process(&mut csv, &mut CsvWriter::new(&mut out))
+            .await
+            .unwrap();
+
+        let expected = r#"geometry,address,type
+POINT(-122.329051 47.6069),"904 7th Av
+Seattle",Car Fire
+POINT(-122.266529 47.515984),9610 53rd Av S,Aid Response
+"#;
+        assert_eq!(expected, String::from_utf8(out).unwrap());
+    }
+
+    #[cfg(feature = "with-tokio")]
+    #[tokio::test]
+    async fn async_matches_sync_reader() {
+        use crate::{AsyncGeozeroDatasource, FeatureRecorder};
+
+        let input = r#"name,geom
+"say ""hi"", then
+leave",POINT(1 1)
+extra,POINT(2 2),field
+last,POINT(3 3)
+"#;
+        let mut sync_features = FeatureRecorder::new();
+        let mut sync_reader =
+            CsvReader::new("geom", input.as_bytes()).with_error_policy(ErrorPolicy::Skip);
+        GeozeroDatasource::process(&mut sync_reader, &mut sync_features).unwrap();
+
+        let mut async_features = FeatureRecorder::new();
+        let mut async_reader =
+            CsvReader::new("geom", input.as_bytes()).with_error_policy(ErrorPolicy::Skip);
+        AsyncGeozeroDatasource::process(&mut async_reader, &mut async_features)
+            .await
+            .unwrap();
+
+        let features = async_features.into_features();
+        assert_eq!(features, sync_features.into_features());
+        assert_eq!(
+            features[0].property_value("name"),
+            Some(&crate::OwnedColumnValue::String(
+                "say \"hi\", then\nleave".to_string()
+            ))
+        );
+        let skipped = |reader: &CsvReader<&[u8]>| {
+            reader
+                .skipped()
+                .iter()
+                .map(|s| (s.idx, s.line))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(skipped(&async_reader), vec![(1, Some(4))]);
+        assert_eq!(skipped(&async_reader), skipped(&sync_reader));
+
+        let err = process_csv_features_async(
+            "name,geom\nextra,POINT(2 2),field\n".as_bytes(),
+            &mut FeatureRecorder::new(),
+            "geom",
+        )
+        .await;
+        assert!(matches!(err, Err(GeozeroError::Dataset(_))));
+    }
 }
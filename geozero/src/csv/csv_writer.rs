@@ -1,13 +1,21 @@
 use crate::error::Result;
 use crate::wkt::WktWriter;
-use crate::{ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
+use crate::{
+    ColumnValue, CoordDimensions, CoordPrecision, FeatureProcessor, GeomProcessor,
+    PropertyProcessor, Schema,
+};
 
+use std::collections::HashSet;
 use std::io::Write;
 
 pub struct CsvWriter<W: Write> {
     csv: csv::Writer<W>,
     headers: Vec<String>,
+    /// Headers declared by a dataset schema
+    has_schema: bool,
     has_written_first_record: bool,
+    /// Properties without a column, which are not written
+    skipped_columns: HashSet<String>,
     current_row_props: Vec<String>,
     wkt_writer: WktWriter<Vec<u8>>,
 }
@@ -21,12 +29,20 @@ impl<W: Write> CsvWriter<W> {
         Self {
             csv: csv::Writer::from_writer(out),
             headers: vec!["geometry".to_string()],
+            has_schema: false,
             has_written_first_record: false,
+            skipped_columns: HashSet::new(),
             current_row_props: vec![],
             wkt_writer: WktWriter::with_dims(vec![], dims),
         }
     }
 
+    /// Write geometry coordinates with the given precision
+    pub fn with_precision(mut self, precision: CoordPrecision) -> Self {
+        self.wkt_writer = self.wkt_writer.with_precision(precision);
+        self
+    }
+
     fn has_started_writing_geometry_in_this_row(&self) -> bool {
         !self.wkt_writer.out.is_empty()
     }
@@ -46,8 +62,17 @@ impl<W: Write> FeatureProcessor for CsvWriter<W> {
         debug_assert_eq!(self.headers, &["geometry"]);
         Ok(())
     }
+    fn schema(&mut self, schema: &Schema) -> Result<()> {
+        debug_assert!(!self.has_written_first_record);
+        self.headers.truncate(1);
+        self.headers
+            .extend(schema.columns.iter().map(|col| col.name.clone()));
+        self.has_schema = true;
+        Ok(())
+    }
     fn dataset_end(&mut self) -> Result<()> {
         self.headers = vec!["geometry".to_string()];
+        self.has_schema = false;
         Ok(())
     }
     fn feature_begin(&mut self, _idx: u64) -> Result<()> {
@@ -65,6 +90,9 @@ impl<W: Write> FeatureProcessor for CsvWriter<W> {
         self.csv.write_field(geom)?;
         self.wkt_writer.out.clear();
 
+        // fill missing properties
+        self.current_row_props
+            .resize(self.headers.len() - 1, String::new());
         for field in &self.current_row_props {
             self.csv.write_field(field)?;
         }
@@ -90,20 +118,29 @@ impl<W: Write> FeatureProcessor for CsvWriter<W> {
 }
 
 impl<W: Write> PropertyProcessor for CsvWriter<W> {
-    fn property(&mut self, i: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
-        // TODO: support mis-ordered properties?
-        if self.has_written_first_record {
-            assert_eq!(
-                colname,
-                &self.headers[i + 1],
-                "CSV features must all have the same column names"
-            );
+    fn property(&mut self, _i: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
+        let col_idx = if self.has_schema || self.has_written_first_record {
+            // Headers are fixed, place the value by column name
+            let Some(col_idx) = self.headers[1..]
+                .iter()
+                .position(|header| header == colname)
+            else {
+                if self.skipped_columns.insert(colname.to_string()) {
+                    log::warn!("skipping property `{colname}` missing in CSV headers");
+                }
+                return Ok(false);
+            };
+            col_idx
         } else {
             self.headers.push(colname.to_string());
-        }
+            self.headers.len() - 2
+        };
 
+        if self.current_row_props.len() <= col_idx {
+            self.current_row_props.resize(col_idx + 1, String::new());
+        }
         // TODO: support non-string colval
-        self.current_row_props.push(colval.to_string());
+        self.current_row_props[col_idx] = colval.to_string();
         Ok(false)
     }
 }
@@ -371,4 +408,75 @@ POINT(1 45),904 7th Av,05/22/2019 12:55:00 PM,F190051945,Car Fire
 
         assert_eq!(expected_output, actual_output);
     }
+
+    #[test]
+    fn misordered_properties() {
+        let input_geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                { "type": "Feature", "properties": { "name": "Foo", "pop": 1 }, "geometry": { "type": "Point", "coordinates": [1, 1] } },
+                { "type": "Feature", "properties": { "pop": 2, "name": "Bar" }, "geometry": { "type": "Point", "coordinates": [2, 2] } },
+                { "type": "Feature", "properties": { "name": "Baz" }, "geometry": { "type": "Point", "coordinates": [3, 3] } }
+            ]
+        }"#;
+
+        let expected_output = r#"geometry,name,pop
+POINT(1 1),Foo,1
+POINT(2 2),Bar,2
+POINT(3 3),Baz,
+"#;
+
+        let actual_output = crate::geojson::GeoJson(input_geojson).to_csv().unwrap();
+
+        assert_eq!(expected_output, actual_output);
+    }
+
+    #[test]
+    fn unknown_property() {
+        let input_geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                { "type": "Feature", "properties": { "name": "Foo" }, "geometry": { "type": "Point", "coordinates": [1, 1] } },
+                { "type": "Feature", "properties": { "other": "Bar" }, "geometry": { "type": "Point", "coordinates": [2, 2] } }
+            ]
+        }"#;
+
+        // Properties missing in the headers of the first feature are skipped
+        let expected = "geometry,name\nPOINT(1 1),Foo\nPOINT(2 2),\n";
+        assert_eq!(
+            crate::geojson::GeoJson(input_geojson).to_csv().unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn schema_headers() {
+        use crate::csv::CsvWriter;
+        use crate::{Column, ColumnType, FeatureProcessor, PropertyProcessor, Schema};
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = CsvWriter::new(&mut out);
+        writer.dataset_begin(None).unwrap();
+        writer
+            .schema(&Schema::new(vec![
+                Column::new("name", Some(ColumnType::String)),
+                Column::new("pop", Some(ColumnType::Int)),
+            ]))
+            .unwrap();
+        writer.feature_begin(0).unwrap();
+        writer.properties_begin().unwrap();
+        writer
+            .property(0, "pop", &crate::ColumnValue::Int(5))
+            .unwrap();
+        // Undeclared properties are skipped
+        writer
+            .property(1, "extra", &crate::ColumnValue::Int(1))
+            .unwrap();
+        writer.properties_end().unwrap();
+        writer.feature_end(0).unwrap();
+        writer.dataset_end().unwrap();
+        drop(writer);
+
+        assert_eq!(String::from_utf8(out).unwrap(), "geometry,name,pop\n,,5\n");
+    }
 }
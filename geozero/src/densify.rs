@@ -0,0 +1,315 @@
+use crate::coord::Coord;
+use crate::error::{GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::PropertyProcessor;
+
+/// Mean earth radius in meters
+const MEAN_EARTH_RADIUS: f64 = 6371008.8;
+
+/// Segment length measure of a [`DensifyProcessor`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DensifyMode {
+    /// Straight segments, length in coordinate units
+    Planar,
+    /// Great circle segments between lon/lat coordinates, length in meters
+    Geodesic,
+}
+
+/// Maximum number of parts a single segment is split into by a [`DensifyProcessor`]
+pub const MAX_SEGMENT_PARTS: usize = 100_000;
+
+/// Number of segments of at most `max_length` covering `length`
+fn segment_count(length: f64, max_length: f64) -> Result<usize> {
+    if !(length.is_finite() && length > max_length) {
+        return Ok(1);
+    }
+    let count = (length / max_length).ceil();
+    if count > MAX_SEGMENT_PARTS as f64 {
+        return Err(GeozeroError::Geometry(format!(
+            "densifying segment of length {length} needs more than {MAX_SEGMENT_PARTS} parts"
+        )));
+    }
+    Ok(count as usize)
+}
+
+/// Unit vector of a lon/lat position in radians
+fn unit_vector(lon: f64, lat: f64) -> [f64; 3] {
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+/// Add points between `a` and `b` following the great circle
+fn densify_geodesic(a: &Coord, b: &Coord, max_length: f64, dense: &mut Vec<Coord>) -> Result<()> {
+    let (lon1, lat1) = (a.x.to_radians(), a.y.to_radians());
+    let (lon2, lat2) = (b.x.to_radians(), b.y.to_radians());
+    // Haversine formula
+    let h = ((lat2 - lat1) / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+    let angle = 2.0 * h.sqrt().min(1.0).asin();
+    let n = segment_count(angle * MEAN_EARTH_RADIUS, max_length)?;
+    let (p1, p2) = (unit_vector(lon1, lat1), unit_vector(lon2, lat2));
+    let sin_angle = angle.sin();
+    for i in 1..n {
+        let f = i as f64 / n as f64;
+        let mut coord = a.interpolate(b, f);
+        // The great circle of antipodal points is undefined, keep the planar position
+        if sin_angle > f64::EPSILON {
+            let ka = ((1.0 - f) * angle).sin() / sin_angle;
+            let kb = (f * angle).sin() / sin_angle;
+            let v = [0, 1, 2].map(|i| ka * p1[i] + kb * p2[i]);
+            coord.x = v[1].atan2(v[0]).to_degrees();
+            coord.y = v[2].atan2(v[0].hypot(v[1])).to_degrees();
+        }
+        dense.push(coord);
+    }
+    Ok(())
+}
+
+/// Processor adding vertices to linestrings and polygon rings
+///
+/// Coordinates of each linestring are buffered, and segments longer than `max_length` are split
+/// into equal parts. Z, M and time values of new vertices are interpolated. Other geometries are
+/// passed unchanged. Segments needing more than [`MAX_SEGMENT_PARTS`] parts fail with an error.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::{DensifyMode, DensifyProcessor, GeozeroGeometry};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor =
+///     DensifyProcessor::new(WktWriter::new(&mut out), DensifyMode::Planar, 2.5).unwrap();
+/// Wkt("LINESTRING(0 0,10 0)").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "LINESTRING(0 0,2.5 0,5 0,7.5 0,10 0)");
+/// # }
+/// ```
+pub struct DensifyProcessor<P> {
+    inner: P,
+    mode: DensifyMode,
+    max_length: f64,
+    /// Coordinates of the current linestring
+    line: Option<Vec<Coord>>,
+}
+
+impl<P: GeomProcessor> DensifyProcessor<P> {
+    /// Split segments longer than `max_length`, which has to be positive
+    pub fn new(inner: P, mode: DensifyMode, max_length: f64) -> Result<Self> {
+        if !(max_length > 0.0 && max_length.is_finite()) {
+            return Err(GeozeroError::Geometry(format!(
+                "invalid maximum segment length {max_length}"
+            )));
+        }
+        Ok(DensifyProcessor {
+            inner,
+            mode,
+            max_length,
+            line: None,
+        })
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn densify(&self, coords: &[Coord]) -> Result<Vec<Coord>> {
+        let mut dense = Vec::with_capacity(coords.len());
+        for segment in coords.windows(2) {
+            let (a, b) = (&segment[0], &segment[1]);
+            dense.push(*a);
+            match self.mode {
+                DensifyMode::Planar => {
+                    let n = segment_count((b.x - a.x).hypot(b.y - a.y), self.max_length)?;
+                    dense.extend((1..n).map(|i| a.interpolate(b, i as f64 / n as f64)));
+                }
+                DensifyMode::Geodesic => densify_geodesic(a, b, self.max_length, &mut dense)?,
+            }
+        }
+        dense.extend(coords.last());
+        Ok(dense)
+    }
+
+    fn coord(&mut self, coord: Coord, idx: usize) -> Result<()> {
+        match &mut self.line {
+            Some(line) => {
+                line.push(coord);
+                Ok(())
+            }
+            None => self.emit(&coord, idx),
+        }
+    }
+
+    fn emit(&mut self, c: &Coord, idx: usize) -> Result<()> {
+        if self.inner.multi_dim() {
+            self.inner.coordinate(c.x, c.y, c.z, c.m, c.t, c.tm, idx)
+        } else {
+            self.inner.xy(c.x, c.y, idx)
+        }
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for DensifyProcessor<P> {
+    forward!(
+        inner => dataset_begin, schema, dataset_end, feature_begin, feature_end, properties_begin,
+        properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for DensifyProcessor<P> {
+    forward!(inner => property);
+}
+
+impl<P: GeomProcessor> GeomProcessor for DensifyProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let coord = Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+            t: None,
+            tm: None,
+        };
+        self.coord(coord, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let coord = Coord { x, y, z, m, t, tm };
+        self.coord(coord, idx)
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.line = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        let coords = self.line.take().unwrap_or_default();
+        let dense = self.densify(&coords)?;
+        self.inner.linestring_begin(tagged, dense.len(), idx)?;
+        for (i, coord) in dense.iter().enumerate() {
+            self.emit(coord, i)?;
+        }
+        self.inner.linestring_end(tagged, idx)
+    }
+    forward!(
+        inner => dimensions, multi_dim, supports_curves, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, multilinestring_begin, multilinestring_end, polygon_begin,
+        polygon_end, multipolygon_begin, multipolygon_end, geometrycollection_begin,
+        geometrycollection_end, circularstring_begin, circularstring_end, compoundcurve_begin,
+        compoundcurve_end, curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end, polyhedralsurface_begin,
+        polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn coord(x: f64, y: f64, z: Option<f64>) -> Coord {
+        Coord {
+            x,
+            y,
+            z,
+            m: None,
+            t: None,
+            tm: None,
+        }
+    }
+
+    fn densify(coords: &[Coord], mode: DensifyMode, max_length: f64) -> Vec<Coord> {
+        DensifyProcessor::new(crate::ProcessorSink, mode, max_length)
+            .unwrap()
+            .densify(coords)
+            .unwrap()
+    }
+
+    #[test]
+    fn planar() {
+        let line = [coord(0.0, 0.0, Some(0.0)), coord(0.0, 3.0, Some(6.0))];
+        let dense = densify(&line, DensifyMode::Planar, 1.0);
+        let xyz: Vec<_> = dense.iter().map(|c| (c.x, c.y, c.z)).collect();
+        assert_eq!(
+            xyz,
+            [
+                (0.0, 0.0, Some(0.0)),
+                (0.0, 1.0, Some(2.0)),
+                (0.0, 2.0, Some(4.0)),
+                (0.0, 3.0, Some(6.0))
+            ]
+        );
+        assert_eq!(densify(&line, DensifyMode::Planar, 5.0).len(), 2);
+        assert_eq!(densify(&line[..1], DensifyMode::Planar, 1.0).len(), 1);
+    }
+
+    #[test]
+    fn limits() {
+        let sink = || crate::ProcessorSink;
+        for max_length in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(DensifyProcessor::new(sink(), DensifyMode::Planar, max_length).is_err());
+        }
+
+        let line = [coord(0.0, 0.0, None), coord(1000.0, 0.0, None)];
+        let processor = DensifyProcessor::new(sink(), DensifyMode::Planar, 1e-9).unwrap();
+        assert!(matches!(
+            processor.densify(&line),
+            Err(GeozeroError::Geometry(_))
+        ));
+        let max_length = 1000.0 / MAX_SEGMENT_PARTS as f64;
+        let processor = DensifyProcessor::new(sink(), DensifyMode::Planar, max_length).unwrap();
+        assert_eq!(
+            processor.densify(&line).unwrap().len(),
+            MAX_SEGMENT_PARTS + 1
+        );
+
+        // About 111 km split into millimeters
+        let line = [coord(0.0, 0.0, None), coord(1.0, 0.0, None)];
+        let processor = DensifyProcessor::new(sink(), DensifyMode::Geodesic, 1e-3).unwrap();
+        assert!(processor.densify(&line).is_err());
+    }
+
+    #[test]
+    fn geodesic() {
+        // A quarter of the equator is about 10'008 km
+        let line = [coord(0.0, 0.0, None), coord(90.0, 0.0, None)];
+        let dense = densify(&line, DensifyMode::Geodesic, 2_600_000.0);
+        let lons: Vec<_> = dense.iter().map(|c| (c.x * 1e6).round() / 1e6).collect();
+        assert_eq!(lons, [0.0, 22.5, 45.0, 67.5, 90.0]);
+        assert!(dense.iter().all(|c| c.y.abs() < 1e-9));
+        assert_eq!(densify(&line, DensifyMode::Geodesic, 10_100_000.0).len(), 2);
+
+        // Great circle between points on the same parallel bends towards the pole
+        let line = [coord(0.0, 45.0, None), coord(90.0, 45.0, None)];
+        let dense = densify(&line, DensifyMode::Geodesic, 4_000_000.0);
+        assert_eq!(dense.len(), 3);
+        assert!((dense[1].x - 45.0).abs() < 1e-9);
+        assert!((dense[1].y - 54.735610317).abs() < 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn densify_rings() {
+        use crate::wkt::{Wkt, WktWriter};
+        use crate::GeozeroGeometry;
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor =
+            DensifyProcessor::new(WktWriter::new(&mut out), DensifyMode::Planar, 1.0).unwrap();
+        Wkt("MULTIPOLYGON(((0 0,2 0,2 1,0 1,0 0)),((5 5,6 5,5 6,5 5)))")
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "MULTIPOLYGON(((0 0,1 0,2 0,2 1,1 1,0 1,0 0)),((5 5,6 5,5.5 5.5,5 6,5 5)))"
+        );
+    }
+}
@@ -0,0 +1,409 @@
+use crate::error::{GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::{CoordDimensions, GeomProcessor};
+use crate::property_processor::{ColumnValue, OwnedColumnValue, PropertyProcessor};
+use crate::recorded::RecordedGeometry;
+use crate::schema::Schema;
+use crate::GeozeroGeometry;
+
+/// Value for a missing Z or M ordinate in a [`DimensionAdapter`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DimensionFill {
+    Constant(f64),
+    /// Numeric value of a feature property
+    Property(String),
+}
+
+#[derive(Default)]
+struct Fill {
+    fill: Option<DimensionFill>,
+    /// Property value of the current feature
+    value: Option<f64>,
+}
+
+impl Fill {
+    fn apply(&self, value: Option<f64>) -> Result<Option<f64>> {
+        match (value, &self.fill) {
+            (Some(value), _) => Ok(Some(value)),
+            (None, None) => Ok(None),
+            (None, Some(DimensionFill::Constant(value))) => Ok(Some(*value)),
+            (None, Some(DimensionFill::Property(name))) => self.value.map(Some).ok_or_else(|| {
+                GeozeroError::Property(format!("no numeric value of `{name}` for missing ordinate"))
+            }),
+        }
+    }
+
+    fn needs_property(&self) -> bool {
+        matches!(self.fill, Some(DimensionFill::Property(_))) && self.value.is_none()
+    }
+
+    fn property(&mut self, name: &str, value: &ColumnValue) {
+        if matches!(&self.fill, Some(DimensionFill::Property(prop)) if prop == name) {
+            self.value = OwnedColumnValue::from(value).as_f64();
+        }
+    }
+}
+
+/// Processor forcing coordinates to target dimensions
+///
+/// Ordinates not in the target dimensions are dropped. Missing Z and M ordinates are
+/// filled with a [`DimensionFill`], or left empty without fill value.
+/// Coordinates are passed to the wrapped processor with `coordinate` or `xy`,
+/// depending on its `multi_dim()`.
+///
+/// When filling from a property emitted after the geometry,
+/// the geometry is buffered and passed on at the end of the feature.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::{CoordDimensions, DimensionAdapter, DimensionFill, GeozeroGeometry};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let writer = WktWriter::with_dims(&mut out, CoordDimensions::xyz());
+/// let mut processor = DimensionAdapter::new(writer, CoordDimensions::xyz())
+///     .fill_z(DimensionFill::Constant(0.0));
+/// Wkt("POINT(1 2)").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "POINT(1 2 0)");
+/// # }
+/// ```
+pub struct DimensionAdapter<P> {
+    inner: P,
+    dims: CoordDimensions,
+    z: Fill,
+    m: Fill,
+    properties_done: bool,
+    /// Geometry buffered until fill values are known
+    recording: Option<RecordedGeometry>,
+    deferred: Option<RecordedGeometry>,
+}
+
+impl<P: GeomProcessor> DimensionAdapter<P> {
+    pub fn new(inner: P, dims: CoordDimensions) -> Self {
+        DimensionAdapter {
+            inner,
+            dims,
+            z: Fill::default(),
+            m: Fill::default(),
+            properties_done: false,
+            recording: None,
+            deferred: None,
+        }
+    }
+
+    /// Fill missing Z values
+    pub fn fill_z(mut self, fill: DimensionFill) -> Self {
+        self.z.fill = Some(fill);
+        self
+    }
+
+    /// Fill missing M values
+    pub fn fill_m(mut self, fill: DimensionFill) -> Self {
+        self.m.fill = Some(fill);
+        self
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn target(&mut self) -> &mut dyn GeomProcessor {
+        match &mut self.recording {
+            Some(geom) => geom,
+            None => &mut self.inner,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn output(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if let Some(geom) = &mut self.recording {
+            return geom.coordinate(x, y, z, m, t, tm, idx);
+        }
+        if !self.inner.multi_dim() {
+            return self.inner.xy(x, y, idx);
+        }
+        let z = if self.dims.z { self.z.apply(z)? } else { None };
+        let m = if self.dims.m { self.m.apply(m)? } else { None };
+        let t = t.filter(|_| self.dims.t);
+        let tm = tm.filter(|_| self.dims.tm);
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for DimensionAdapter<P> {
+    fn schema(&mut self, schema: &Schema) -> Result<()> {
+        let schema = Schema {
+            dims: self.dims,
+            ..schema.clone()
+        };
+        self.inner.schema(&schema)
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        self.z.value = None;
+        self.m.value = None;
+        self.properties_done = false;
+        self.inner.feature_begin(idx)
+    }
+    fn feature_end(&mut self, idx: u64) -> Result<()> {
+        if let Some(geom) = self.deferred.take() {
+            self.inner.geometry_begin()?;
+            geom.process_geom(self)?;
+            self.inner.geometry_end()?;
+        }
+        self.inner.feature_end(idx)
+    }
+    fn properties_end(&mut self) -> Result<()> {
+        self.properties_done = true;
+        self.inner.properties_end()
+    }
+    fn geometry_begin(&mut self) -> Result<()> {
+        if !self.properties_done && (self.z.needs_property() || self.m.needs_property()) {
+            self.recording = Some(RecordedGeometry::new());
+            return Ok(());
+        }
+        self.inner.geometry_begin()
+    }
+    fn geometry_end(&mut self) -> Result<()> {
+        if let Some(geom) = self.recording.take() {
+            self.deferred = Some(geom);
+            return Ok(());
+        }
+        self.inner.geometry_end()
+    }
+    forward!(inner => dataset_begin, dataset_end, properties_begin);
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for DimensionAdapter<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.z.property(name, value);
+        self.m.property(name, value);
+        self.inner.property(idx, name, value)
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for DimensionAdapter<P> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.dims
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.target().srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.output(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.output(x, y, z, m, t, tm, idx)
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        self.target().empty_point(idx)
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.target().point_begin(idx)
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.target().point_end(idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().multipoint_begin(size, idx)
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.target().multipoint_end(idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.target().linestring_begin(tagged, size, idx)
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.target().linestring_end(tagged, idx)
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().multilinestring_begin(size, idx)
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.target().multilinestring_end(idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.target().polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.target().polygon_end(tagged, idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().multipolygon_begin(size, idx)
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.target().multipolygon_end(idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().geometrycollection_begin(size, idx)
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.target().geometrycollection_end(idx)
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().circularstring_begin(size, idx)
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.target().circularstring_end(idx)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().compoundcurve_begin(size, idx)
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.target().compoundcurve_end(idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().curvepolygon_begin(size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.target().curvepolygon_end(idx)
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().multicurve_begin(size, idx)
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.target().multicurve_end(idx)
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().multisurface_begin(size, idx)
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.target().multisurface_end(idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.target().triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.target().triangle_end(tagged, idx)
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().polyhedralsurface_begin(size, idx)
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.target().polyhedralsurface_end(idx)
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().tin_begin(size, idx)
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.target().tin_end(idx)
+    }
+    forward!(inner => supports_curves);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn force_dimensions() {
+        use crate::wkt::{Wkt, WktWriter};
+
+        let convert = |wkt: &str, dims: CoordDimensions, fill: Option<f64>| {
+            let mut out: Vec<u8> = Vec::new();
+            let writer = WktWriter::with_dims(&mut out, CoordDimensions::xyzm());
+            let mut processor = DimensionAdapter::new(writer, dims);
+            if let Some(value) = fill {
+                processor = processor
+                    .fill_z(DimensionFill::Constant(value))
+                    .fill_m(DimensionFill::Constant(value));
+            }
+            Wkt(wkt).process_geom(&mut processor).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(
+            convert("LINESTRING(1 2,3 4)", CoordDimensions::xyz(), Some(5.0)),
+            "LINESTRING(1 2 5,3 4 5)"
+        );
+        assert_eq!(
+            convert("POINT(1 2)", CoordDimensions::xyzm(), Some(0.0)),
+            "POINT(1 2 0 0)"
+        );
+        assert_eq!(
+            convert("POINT(1 2)", CoordDimensions::xyz(), None),
+            "POINT(1 2)"
+        );
+        assert_eq!(
+            convert("POINT(1 2)", CoordDimensions::xy(), Some(0.0)),
+            "POINT(1 2)"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "with-geojson", feature = "with-wkt"))]
+    fn drop_and_fill_from_property() {
+        use crate::geojson::{GeoJson, GeoJsonLineReader, GeoJsonWriter};
+        use crate::{FeatureRecorder, GeozeroDatasource, ToWkt};
+
+        // Drop Z
+        let mut out: Vec<u8> = Vec::new();
+        let writer = GeoJsonWriter::with_dims(&mut out, CoordDimensions::xyz());
+        let mut processor = DimensionAdapter::new(writer, CoordDimensions::xy());
+        GeoJson(r#"{"type": "Point", "coordinates": [1, 2, 3]}"#)
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            r#"{"type": "Point", "coordinates": [1,2]}"#
+        );
+
+        // Property emitted after geometry
+        let mut processor = DimensionAdapter::new(FeatureRecorder::new(), CoordDimensions::xyz())
+            .fill_z(DimensionFill::Property("height".to_string()));
+        processor.dataset_begin(None).unwrap();
+        for (idx, z) in [(0, None), (1, Some(7.0))] {
+            processor.feature_begin(idx).unwrap();
+            processor.geometry_begin().unwrap();
+            processor.point_begin(0).unwrap();
+            processor
+                .coordinate(1.0, 2.0, z, None, None, None, 0)
+                .unwrap();
+            processor.point_end(0).unwrap();
+            processor.geometry_end().unwrap();
+            processor.properties_begin().unwrap();
+            processor
+                .property(0, "height", &ColumnValue::Long(10))
+                .unwrap();
+            processor.properties_end().unwrap();
+            processor.feature_end(idx).unwrap();
+        }
+        processor.dataset_end().unwrap();
+        let wkt = processor
+            .into_inner()
+            .into_features()
+            .iter()
+            .map(|feature| feature.to_wkt_ndim(CoordDimensions::xyz()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(wkt, ["POINT(1 2 10)", "POINT(1 2 7)"]);
+
+        let input = r#"{"type": "Feature", "properties": {"name": "x"}, "geometry": {"type": "Point", "coordinates": [1, 2]}}"#;
+        let mut processor = DimensionAdapter::new(FeatureRecorder::new(), CoordDimensions::xyz())
+            .fill_z(DimensionFill::Property("height".to_string()));
+        assert!(matches!(
+            GeoJsonLineReader::new(input.as_bytes()).process(&mut processor),
+            Err(GeozeroError::Property(_))
+        ));
+    }
+}
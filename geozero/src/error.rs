@@ -36,6 +36,15 @@ pub enum GeozeroError {
     Srid(i32),
     #[error("processing geometry `{0}`")]
     Geometry(String),
+    // Processors
+    #[error("invalid filter expression `{0}`")]
+    FilterExpression(String),
+    #[error("reprojection `{0}`")]
+    Reprojection(String),
+    #[error("invalid event stream: {0}")]
+    Validation(String),
+    #[error("invalid tile `{0}`")]
+    Tile(String),
     // General
     #[error("I/O error `{0}`")]
     IoError(#[from] std::io::Error),
@@ -48,3 +57,58 @@ pub enum GeozeroError {
 }
 
 pub type Result<T> = std::result::Result<T, GeozeroError>;
+
+/// Handling of invalid features in readers
+///
+/// The policy covers only records failing to parse, which are rejected before any event of the
+/// feature is emitted. I/O errors and errors raised after `feature_begin`, such as processor
+/// errors, always abort processing, leaving the feature open.
+///
+/// The policy applies to processing a reader with `process`, the
+/// [`FeatureIter`](crate::FeatureIter) returned by `features()` stops at the first invalid feature
+/// regardless.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Stop processing at the first invalid feature
+    #[default]
+    Abort,
+    /// Skip invalid features, reporting their position only
+    Skip,
+    /// Skip invalid features, reporting their position and error
+    Collect,
+}
+
+/// Invalid feature skipped by a reader
+#[derive(Debug)]
+pub struct SkippedFeature {
+    /// Index of the invalid record in the input, counting all records
+    ///
+    /// Features passed on are numbered consecutively without the skipped ones, so this is not
+    /// the `feature_begin` index of any processed feature.
+    pub idx: u64,
+    /// Line number in the input, starting at 1
+    pub line: Option<u64>,
+    /// Reader error, reported with [`ErrorPolicy::Collect`]
+    pub error: Option<GeozeroError>,
+}
+
+#[cfg(any(feature = "with-csv", feature = "with-geojson"))]
+impl ErrorPolicy {
+    /// Fail with `error` when aborting, otherwise add the feature to `skipped`.
+    pub(crate) fn skip(
+        self,
+        error: GeozeroError,
+        idx: u64,
+        line: Option<u64>,
+        skipped: &mut Vec<SkippedFeature>,
+    ) -> Result<()> {
+        let error = match self {
+            _ if matches!(error, GeozeroError::IoError(_)) => return Err(error),
+            ErrorPolicy::Abort => return Err(error),
+            ErrorPolicy::Skip => None,
+            ErrorPolicy::Collect => Some(error),
+        };
+        skipped.push(SkippedFeature { idx, line, error });
+        Ok(())
+    }
+}
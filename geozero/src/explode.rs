@@ -0,0 +1,345 @@
+use crate::clip::{Geom, GeomBuilder};
+use crate::coord::Coord;
+use crate::error::{GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use crate::recorded::{FeatureRecorder, RecordedFeature, RecordedGeometry};
+
+/// Add single parts of `geom` to `parts`, empty multi-geometries are kept as they are
+fn explode(geom: Geom, parts: &mut Vec<Geom>) {
+    match geom {
+        Geom::MultiPoint(points) if !points.is_empty() => {
+            parts.extend(points.into_iter().map(|c| Geom::Point(Some(c))));
+        }
+        Geom::MultiLineString(lines) if !lines.is_empty() => {
+            parts.extend(lines.into_iter().map(Geom::LineString));
+        }
+        Geom::MultiPolygon(polygons) if !polygons.is_empty() => {
+            parts.extend(polygons.into_iter().map(Geom::Polygon));
+        }
+        Geom::GeometryCollection(members) if !members.is_empty() => {
+            for member in members {
+                explode(member, parts);
+            }
+        }
+        geom => parts.push(geom),
+    }
+}
+
+/// Processor splitting multi-geometry features into one feature per part
+///
+/// The events of each feature are buffered until `feature_end`. Features with a multi-geometry
+/// or geometry collection are replayed once for each single part, with the same properties.
+/// Feature indices stay consecutive. Geometries outside of features are passed unchanged.
+///
+/// Curves are linearized by readers, other curve, triangle and surface events are rejected.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::geojson::GeoJsonLineReader;
+/// use geozero::{Explode, FeatureRecorder, GeozeroDatasource};
+///
+/// let input = r#"{"type": "Feature", "properties": {"name": "a"}, "geometry": {"type": "MultiPoint", "coordinates": [[1, 1], [2, 2]]}}"#;
+///
+/// let mut explode = Explode::new(FeatureRecorder::new());
+/// GeoJsonLineReader::new(input.as_bytes()).process(&mut explode).unwrap();
+/// assert_eq!(explode.into_inner().features().len(), 2);
+/// # }
+/// ```
+pub struct Explode<P> {
+    inner: P,
+    geom: GeomBuilder,
+    /// Properties of the current feature
+    recorder: Option<FeatureRecorder>,
+    /// Top level geometries of the current feature
+    geoms: Vec<Geom>,
+    srid: Option<Option<i32>>,
+    next_idx: u64,
+}
+
+impl<P: FeatureProcessor> Explode<P> {
+    pub fn new(inner: P) -> Self {
+        Explode {
+            inner,
+            geom: GeomBuilder::default(),
+            recorder: None,
+            geoms: Vec::new(),
+            srid: None,
+            next_idx: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Keep a completed top level geometry of a feature, or pass it on
+    fn emit(&mut self, geom: Option<Geom>, idx: usize) -> Result<()> {
+        match geom {
+            Some(geom) if self.recorder.is_some() => self.geoms.push(geom),
+            Some(geom) => {
+                let multi_dim = self.inner.multi_dim();
+                geom.process(&mut self.inner, multi_dim, idx)?;
+            }
+            None => {}
+        }
+        Ok(())
+    }
+
+    fn replay(&mut self, mut feature: RecordedFeature) -> Result<()> {
+        feature.idx = self.next_idx;
+        self.next_idx += 1;
+        feature.process(&mut self.inner)
+    }
+
+    fn record(&self, geom: &Geom) -> Result<RecordedGeometry> {
+        let mut recorded = RecordedGeometry::new();
+        if let Some(srid) = self.srid {
+            recorded.srid(srid)?;
+        }
+        geom.process(&mut recorded, true, 0)?;
+        Ok(recorded)
+    }
+
+    fn unsupported(&self, geometry_type: &str) -> Result<()> {
+        Err(GeozeroError::Geometry(format!(
+            "exploding {geometry_type} is not supported"
+        )))
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for Explode<P> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.next_idx = 0;
+        self.inner.dataset_begin(name)
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        let mut recorder = FeatureRecorder::default();
+        recorder.feature_begin(idx)?;
+        self.recorder = Some(recorder);
+        self.geoms.clear();
+        self.srid = None;
+        Ok(())
+    }
+    fn feature_end(&mut self, _idx: u64) -> Result<()> {
+        let Some(feature) = self
+            .recorder
+            .take()
+            .and_then(|recorder| recorder.into_features().pop())
+        else {
+            return Err(GeozeroError::Feature(
+                "feature_end without feature_begin".to_string(),
+            ));
+        };
+        let mut parts = Vec::new();
+        for geom in std::mem::take(&mut self.geoms) {
+            explode(geom, &mut parts);
+        }
+        if parts.is_empty() {
+            return self.replay(feature);
+        }
+        for part in &parts {
+            let geometry = Some(self.record(part)?);
+            self.replay(RecordedFeature {
+                geometry,
+                ..feature.clone()
+            })?;
+        }
+        Ok(())
+    }
+    fn geometry_begin(&mut self) -> Result<()> {
+        match self.recorder {
+            Some(ref mut recorder) => recorder.geometry_begin(),
+            None => self.inner.geometry_begin(),
+        }
+    }
+    fn geometry_end(&mut self) -> Result<()> {
+        match self.recorder {
+            Some(ref mut recorder) => recorder.geometry_end(),
+            None => self.inner.geometry_end(),
+        }
+    }
+    forward!(inner => schema, dataset_end);
+}
+
+impl<P: FeatureProcessor> PropertyProcessor for Explode<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        match self.recorder {
+            Some(ref mut recorder) => recorder.property(idx, name, value),
+            None => self.inner.property(idx, name, value),
+        }
+    }
+}
+
+impl<P: FeatureProcessor> GeomProcessor for Explode<P> {
+    fn supports_curves(&self) -> bool {
+        false
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        match self.recorder {
+            Some(_) => {
+                self.srid = Some(srid);
+                Ok(())
+            }
+            None => self.inner.srid(srid),
+        }
+    }
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.geom.coord(Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+            t: None,
+            tm: None,
+        })
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        self.geom.coord(Coord { x, y, z, m, t, tm })
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.add(Geom::Point(None))?;
+        self.emit(geom, idx)
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.geom.begin(Geom::Point(None));
+        Ok(())
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom.begin(Geom::MultiPoint(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.geom.line_begin(size);
+        Ok(())
+    }
+    fn linestring_end(&mut self, _tagged: bool, idx: usize) -> Result<()> {
+        let geom = self.geom.line_end()?;
+        self.emit(geom, idx)
+    }
+    fn multilinestring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom
+            .begin(Geom::MultiLineString(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn polygon_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.geom.begin(Geom::Polygon(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn polygon_end(&mut self, _tagged: bool, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom
+            .begin(Geom::MultiPolygon(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.geom
+            .begin(Geom::GeometryCollection(Vec::with_capacity(size)));
+        Ok(())
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        let geom = self.geom.end()?;
+        self.emit(geom, idx)
+    }
+    fn circularstring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("circularstring")
+    }
+    fn compoundcurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("compoundcurve")
+    }
+    fn curvepolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("curvepolygon")
+    }
+    fn multicurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("multicurve")
+    }
+    fn multisurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("multisurface")
+    }
+    fn triangle_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("triangle")
+    }
+    fn polyhedralsurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("polyhedralsurface")
+    }
+    fn tin_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.unsupported("tin")
+    }
+    forward!(inner => dimensions, multi_dim);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "with-geojson", feature = "with-wkt"))]
+    fn explode_features() {
+        use crate::geojson::GeoJsonLineReader;
+        use crate::wkt::WktWriter;
+        use crate::{GeozeroDatasource, GeozeroGeometry, OwnedColumnValue};
+
+        let input = r#"{"type": "Feature", "properties": {"name": "a"}, "geometry": {"type": "MultiPolygon", "coordinates": [[[[0, 0], [1, 0], [1, 1], [0, 0]]], [[[5, 5], [6, 5], [6, 6], [5, 5]]]]}}
+{"type": "Feature", "properties": {"name": "b"}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+{"type": "Feature", "geometry": {"type": "GeometryCollection", "geometries": [{"type": "Point", "coordinates": [2, 2]}, {"type": "MultiLineString", "coordinates": [[[0, 0], [1, 1]]]}]}, "properties": {"name": "c"}}"#;
+        let mut explode = Explode::new(FeatureRecorder::new());
+        GeoJsonLineReader::new(input.as_bytes())
+            .process(&mut explode)
+            .unwrap();
+        let features = explode.into_inner().into_features();
+
+        let wkt = |feature: &RecordedFeature| {
+            let mut out: Vec<u8> = Vec::new();
+            feature.process_geom(&mut WktWriter::new(&mut out)).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        let name = |feature: &RecordedFeature| match feature.property_value("name") {
+            Some(OwnedColumnValue::String(name)) => name.clone(),
+            _ => String::new(),
+        };
+        let exploded: Vec<_> = features.iter().map(|f| (f.idx, name(f), wkt(f))).collect();
+        assert_eq!(
+            exploded,
+            [
+                (0, "a".to_string(), "POLYGON((0 0,1 0,1 1,0 0))".to_string()),
+                (1, "a".to_string(), "POLYGON((5 5,6 5,6 6,5 5))".to_string()),
+                (2, "b".to_string(), "POINT(1 1)".to_string()),
+                (3, "c".to_string(), "POINT(2 2)".to_string()),
+                (4, "c".to_string(), "LINESTRING(0 0,1 1)".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::error::Result;
+use crate::recorded::{FeatureRecorder, RecordedFeature};
+use crate::FeatureProcessor;
+
+/// Datasource which can process its features one at a time.
+pub trait FeatureSource {
+    /// Process the next feature with positional index `idx`.
+    ///
+    /// Emits the events between `feature_begin` and `feature_end`, but no dataset events.
+    /// Returns `false` when there are no more features.
+    fn process_next_feature<P: FeatureProcessor>(
+        &mut self,
+        idx: u64,
+        processor: &mut P,
+    ) -> Result<bool>;
+}
+
+/// Pull-based iterator yielding owned features of a [`FeatureSource`]
+///
+/// Iteration stops after the first error, the [`ErrorPolicy`](crate::error::ErrorPolicy) of the
+/// reader is not applied.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::geojson::GeoJsonLineReader;
+/// use geozero::OwnedColumnValue;
+///
+/// let input = r#"{"type": "Feature", "properties": {"name": "Zurich"}, "geometry": {"type": "Point", "coordinates": [8.5, 47.4]}}
+/// {"type": "Feature", "properties": {"name": "Bern"}, "geometry": {"type": "Point", "coordinates": [7.4, 46.9]}}"#;
+/// let reader = GeoJsonLineReader::new(input.as_bytes());
+/// for feature in reader.features().take(1) {
+///     let feature = feature.unwrap();
+///     assert_eq!(
+///         feature.property_value("name"),
+///         Some(&OwnedColumnValue::String("Zurich".to_string()))
+///     );
+/// }
+/// # }
+/// ```
+pub struct FeatureIter<S> {
+    source: S,
+    next_idx: u64,
+    done: bool,
+}
+
+impl<S: FeatureSource> FeatureIter<S> {
+    pub fn new(source: S) -> Self {
+        FeatureIter {
+            source,
+            next_idx: 0,
+            done: false,
+        }
+    }
+
+    /// Unwrap the underlying feature source
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: FeatureSource> Iterator for FeatureIter<S> {
+    type Item = Result<RecordedFeature>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut recorder = FeatureRecorder::default();
+        match self
+            .source
+            .process_next_feature(self.next_idx, &mut recorder)
+        {
+            Ok(true) => {
+                self.next_idx += 1;
+                Some(Ok(recorder.into_features().pop().unwrap_or_default()))
+            }
+            Ok(false) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<S: FeatureSource> std::iter::FusedIterator for FeatureIter<S> {}
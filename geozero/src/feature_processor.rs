@@ -1,6 +1,7 @@
 use crate::error::Result;
 use crate::geometry_processor::GeomProcessor;
 use crate::property_processor::PropertyProcessor;
+use crate::schema::Schema;
 
 /// Feature processing trait
 #[allow(unused_variables)]
@@ -16,6 +17,18 @@ pub trait FeatureProcessor: GeomProcessor + PropertyProcessor {
     fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
         Ok(())
     }
+    /// Dataset schema declaration
+    ///
+    /// Called by readers knowing their columns and geometry type up front.
+    ///
+    /// ## Invariants
+    ///
+    /// - `schema` is optional and called _at most once_ for an entire dataset.
+    /// - `schema` is called after `dataset_begin` and before the first `feature_begin`.
+    /// - Features may still contain properties which are not declared in the schema.
+    fn schema(&mut self, schema: &Schema) -> Result<()> {
+        Ok(())
+    }
     /// End of dataset processing
     ///
     /// ## Invariants
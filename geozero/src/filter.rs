@@ -0,0 +1,707 @@
+use crate::error::{GeozeroError, Result};
+use crate::forward::forward;
+use crate::recorded::{FeatureRecorder, RecordedFeature};
+use crate::{ColumnValue, FeatureProcessor, GeomProcessor, OwnedColumnValue, PropertyProcessor};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Feature predicate of a [`FilterProcessor`]
+pub trait FeaturePredicate {
+    /// Keep `feature` if `true` is returned
+    fn matches(&mut self, feature: &RecordedFeature) -> bool;
+}
+
+impl<F: FnMut(&RecordedFeature) -> bool> FeaturePredicate for F {
+    fn matches(&mut self, feature: &RecordedFeature) -> bool {
+        self(feature)
+    }
+}
+
+/// Processor forwarding only features matching a predicate
+///
+/// The events of each feature are buffered until `feature_end`. Matching features are
+/// replayed into the wrapped processor with consecutive feature indices, others are dropped.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::geojson::{GeoJsonLineReader, GeoJsonWriter};
+/// use geozero::{FilterProcessor, GeozeroDatasource, OwnedColumnValue, RecordedFeature};
+///
+/// let input = r#"{"type": "Feature", "properties": {"name": "a"}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+/// {"type": "Feature", "properties": {"name": "b"}, "geometry": {"type": "Point", "coordinates": [2, 2]}}"#;
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut filter = FilterProcessor::new(GeoJsonWriter::new(&mut out), |feature: &RecordedFeature| {
+///     feature.property_value("name") == Some(&OwnedColumnValue::String("b".to_string()))
+/// });
+/// GeoJsonLineReader::new(input.as_bytes()).process(&mut filter).unwrap();
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut filter = FilterProcessor::with_expr(GeoJsonWriter::new(&mut out), "name = 'b'").unwrap();
+/// GeoJsonLineReader::new(input.as_bytes()).process(&mut filter).unwrap();
+/// # }
+/// ```
+pub struct FilterProcessor<P, F> {
+    inner: P,
+    predicate: F,
+    recorder: Option<FeatureRecorder>,
+    next_idx: u64,
+}
+
+impl<P: FeatureProcessor, F: FeaturePredicate> FilterProcessor<P, F> {
+    pub fn new(inner: P, predicate: F) -> Self {
+        FilterProcessor {
+            inner,
+            predicate,
+            recorder: None,
+            next_idx: 0,
+        }
+    }
+
+    /// Unwrap the inner processor
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Events of the current feature are recorded, other events are forwarded
+    fn target(&mut self) -> &mut dyn GeomProcessor {
+        match self.recorder {
+            Some(ref mut recorder) => recorder,
+            None => &mut self.inner,
+        }
+    }
+}
+
+impl<P: FeatureProcessor> FilterProcessor<P, FilterExpr> {
+    /// Filter with a [`FilterExpr`] like `name = 'x' AND pop > 5`
+    pub fn with_expr(inner: P, expr: &str) -> Result<Self> {
+        Ok(Self::new(inner, expr.parse()?))
+    }
+}
+
+impl<P: FeatureProcessor, F: FeaturePredicate> FeatureProcessor for FilterProcessor<P, F> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.next_idx = 0;
+        self.inner.dataset_begin(name)
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        let mut recorder = FeatureRecorder::default();
+        recorder.feature_begin(idx)?;
+        self.recorder = Some(recorder);
+        Ok(())
+    }
+    fn feature_end(&mut self, _idx: u64) -> Result<()> {
+        let Some(mut feature) = self
+            .recorder
+            .take()
+            .and_then(|recorder| recorder.into_features().pop())
+        else {
+            return Err(GeozeroError::Feature(
+                "feature_end without feature_begin".to_string(),
+            ));
+        };
+        if self.predicate.matches(&feature) {
+            feature.idx = self.next_idx;
+            self.next_idx += 1;
+            feature.process(&mut self.inner)?;
+        }
+        Ok(())
+    }
+    fn geometry_begin(&mut self) -> Result<()> {
+        match self.recorder {
+            Some(ref mut recorder) => recorder.geometry_begin(),
+            None => self.inner.geometry_begin(),
+        }
+    }
+    fn geometry_end(&mut self) -> Result<()> {
+        match self.recorder {
+            Some(ref mut recorder) => recorder.geometry_end(),
+            None => self.inner.geometry_end(),
+        }
+    }
+    forward!(inner => schema, dataset_end);
+}
+
+impl<P: FeatureProcessor, F: FeaturePredicate> PropertyProcessor for FilterProcessor<P, F> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        match self.recorder {
+            Some(ref mut recorder) => recorder.property(idx, name, value),
+            None => self.inner.property(idx, name, value),
+        }
+    }
+}
+
+impl<P: FeatureProcessor, F: FeaturePredicate> GeomProcessor for FilterProcessor<P, F> {
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.target().srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.target().xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.target().coordinate(x, y, z, m, t, tm, idx)
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        self.target().empty_point(idx)
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.target().point_begin(idx)
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.target().point_end(idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().multipoint_begin(size, idx)
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.target().multipoint_end(idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.target().linestring_begin(tagged, size, idx)
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.target().linestring_end(tagged, idx)
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().multilinestring_begin(size, idx)
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.target().multilinestring_end(idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.target().polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.target().polygon_end(tagged, idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().multipolygon_begin(size, idx)
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.target().multipolygon_end(idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().geometrycollection_begin(size, idx)
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.target().geometrycollection_end(idx)
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().circularstring_begin(size, idx)
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.target().circularstring_end(idx)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().compoundcurve_begin(size, idx)
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.target().compoundcurve_end(idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().curvepolygon_begin(size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.target().curvepolygon_end(idx)
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().multicurve_begin(size, idx)
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.target().multicurve_end(idx)
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().multisurface_begin(size, idx)
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.target().multisurface_end(idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.target().triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.target().triangle_end(tagged, idx)
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().polyhedralsurface_begin(size, idx)
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.target().polyhedralsurface_end(idx)
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.target().tin_begin(size, idx)
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.target().tin_end(idx)
+    }
+    forward!(inner => dimensions, multi_dim, supports_curves);
+}
+
+/// Property filter expression
+///
+/// Syntax:
+/// * Comparisons of a property with a literal: `=`, `!=` or `<>`, `<`, `<=`, `>`, `>=`
+/// * Literals: `'single quoted strings'`, numbers, `TRUE` and `FALSE`
+/// * Property names: identifiers like `pop_2020` or `"double quoted names"`
+/// * `name IS NULL`, `name IS NOT NULL`
+/// * `AND`, `OR`, `NOT` and parentheses
+///
+/// Keywords are case-insensitive. Numeric comparisons also apply to string values
+/// containing numbers, like CSV columns. Comparisons with missing or null values are false.
+///
+/// ```rust
+/// use geozero::FilterExpr;
+///
+/// let expr: FilterExpr = "name = 'x' AND (pop > 5 OR capital IS NOT NULL)".parse().unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterExpr {
+    Compare(String, CompareOp, FilterLiteral),
+    IsNull(String),
+    Not(Box<FilterExpr>),
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    Or(Box<FilterExpr>, Box<FilterExpr>),
+}
+
+/// Comparison operator of a [`FilterExpr`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Literal value of a [`FilterExpr`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterLiteral {
+    String(String),
+    Number(f64),
+    Bool(bool),
+}
+
+impl FilterExpr {
+    /// Evaluate expression for `feature`
+    pub fn eval(&self, feature: &RecordedFeature) -> bool {
+        match self {
+            FilterExpr::Compare(name, op, literal) => feature
+                .property_value(name)
+                .and_then(|value| compare(value, literal))
+                .is_some_and(|ordering| op.matches(ordering)),
+            FilterExpr::IsNull(name) => {
+                matches!(
+                    feature.property_value(name),
+                    None | Some(OwnedColumnValue::Null)
+                )
+            }
+            FilterExpr::Not(expr) => !expr.eval(feature),
+            FilterExpr::And(lhs, rhs) => lhs.eval(feature) && rhs.eval(feature),
+            FilterExpr::Or(lhs, rhs) => lhs.eval(feature) || rhs.eval(feature),
+        }
+    }
+}
+
+impl FeaturePredicate for FilterExpr {
+    fn matches(&mut self, feature: &RecordedFeature) -> bool {
+        self.eval(feature)
+    }
+}
+
+impl CompareOp {
+    fn matches(self, ordering: Ordering) -> bool {
+        match self {
+            CompareOp::Eq => ordering == Ordering::Equal,
+            CompareOp::Ne => ordering != Ordering::Equal,
+            CompareOp::Lt => ordering == Ordering::Less,
+            CompareOp::Le => ordering != Ordering::Greater,
+            CompareOp::Gt => ordering == Ordering::Greater,
+            CompareOp::Ge => ordering != Ordering::Less,
+        }
+    }
+}
+
+/// Ordering of `value` relative to `literal`, `None` if not comparable
+fn compare(value: &OwnedColumnValue, literal: &FilterLiteral) -> Option<Ordering> {
+    match (value, literal) {
+        (OwnedColumnValue::Null, _) => None,
+        (_, FilterLiteral::Number(n)) => value.as_f64()?.partial_cmp(n),
+        (OwnedColumnValue::Bool(v), FilterLiteral::Bool(b)) => Some(v.cmp(b)),
+        (OwnedColumnValue::String(v), FilterLiteral::Bool(b)) => {
+            let v = v.parse::<bool>().ok()?;
+            Some(v.cmp(b))
+        }
+        (_, FilterLiteral::Bool(_)) => None,
+        (
+            OwnedColumnValue::String(v) | OwnedColumnValue::Json(v) | OwnedColumnValue::DateTime(v),
+            FilterLiteral::String(s),
+        ) => Some(v.as_str().cmp(s.as_str())),
+        (_, FilterLiteral::String(s)) => Some(value.to_string().as_str().cmp(s.as_str())),
+    }
+}
+
+impl FromStr for FilterExpr {
+    type Err = GeozeroError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let tokens = tokenize(s)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(expr),
+            Some(token) => Err(GeozeroError::FilterExpression(format!(
+                "unexpected {token:?}"
+            ))),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Number(f64),
+    Op(CompareOp),
+    LParen,
+    RParen,
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RParen);
+            }
+            '\'' | '"' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        // a doubled quote is an escaped quote
+                        Some(q) if q == c && chars.peek() == Some(&c) => {
+                            chars.next();
+                            text.push(c);
+                        }
+                        Some(q) if q == c => break,
+                        Some(ch) => text.push(ch),
+                        None => {
+                            return Err(GeozeroError::FilterExpression(format!(
+                                "unterminated {c}{text}"
+                            )))
+                        }
+                    }
+                }
+                tokens.push(if c == '\'' {
+                    Token::Str(text)
+                } else {
+                    Token::Ident(text)
+                });
+            }
+            '=' | '!' | '<' | '>' => {
+                chars.next();
+                let next = chars.peek().copied();
+                let op = match (c, next) {
+                    ('=', _) => CompareOp::Eq,
+                    ('!', Some('=')) | ('<', Some('>')) => CompareOp::Ne,
+                    ('<', Some('=')) => CompareOp::Le,
+                    ('>', Some('=')) => CompareOp::Ge,
+                    ('<', _) => CompareOp::Lt,
+                    ('>', _) => CompareOp::Gt,
+                    _ => return Err(GeozeroError::FilterExpression(format!("unexpected {c}"))),
+                };
+                if matches!(op, CompareOp::Ne | CompareOp::Le | CompareOp::Ge) {
+                    chars.next();
+                }
+                tokens.push(Token::Op(op));
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let mut text = String::new();
+                while let Some(&ch) = chars.peek() {
+                    if ch.is_ascii_alphanumeric() || matches!(ch, '-' | '+' | '.') {
+                        text.push(ch);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let number = text.parse().map_err(|_| {
+                    GeozeroError::FilterExpression(format!("invalid number {text}"))
+                })?;
+                tokens.push(Token::Number(number));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut text = String::new();
+                while let Some(&ch) = chars.peek() {
+                    if ch.is_alphanumeric() || ch == '_' {
+                        text.push(ch);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Ident(text));
+            }
+            _ => return Err(GeozeroError::FilterExpression(format!("unexpected {c}"))),
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// Consume keyword `kw`, if it is the next token
+    fn keyword(&mut self, kw: &str) -> bool {
+        match self.tokens.get(self.pos) {
+            Some(Token::Ident(ident)) if ident.eq_ignore_ascii_case(kw) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<FilterExpr> {
+        let mut expr = self.parse_and()?;
+        while self.keyword("OR") {
+            expr = FilterExpr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<FilterExpr> {
+        let mut expr = self.parse_not()?;
+        while self.keyword("AND") {
+            expr = FilterExpr::And(Box::new(expr), Box::new(self.parse_not()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<FilterExpr> {
+        if self.keyword("NOT") {
+            Ok(FilterExpr::Not(Box::new(self.parse_not()?)))
+        } else {
+            self.parse_primary()
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<FilterExpr> {
+        let name = match self.next() {
+            Some(Token::LParen) => {
+                let expr = self.parse_or()?;
+                return match self.next() {
+                    Some(Token::RParen) => Ok(expr),
+                    _ => Err(GeozeroError::FilterExpression(
+                        "missing closing parenthesis".to_string(),
+                    )),
+                };
+            }
+            Some(Token::Ident(name)) => name,
+            token => {
+                return Err(GeozeroError::FilterExpression(format!(
+                    "expected property name, found {token:?}"
+                )))
+            }
+        };
+        if self.keyword("IS") {
+            let negated = self.keyword("NOT");
+            if !self.keyword("NULL") {
+                return Err(GeozeroError::FilterExpression(
+                    "expected NULL after IS".to_string(),
+                ));
+            }
+            let expr = FilterExpr::IsNull(name);
+            return Ok(if negated {
+                FilterExpr::Not(Box::new(expr))
+            } else {
+                expr
+            });
+        }
+        let Some(Token::Op(op)) = self.next() else {
+            return Err(GeozeroError::FilterExpression(format!(
+                "expected comparison operator after {name}"
+            )));
+        };
+        let literal = match self.next() {
+            Some(Token::Str(s)) => FilterLiteral::String(s),
+            Some(Token::Number(n)) => FilterLiteral::Number(n),
+            Some(Token::Ident(kw)) if kw.eq_ignore_ascii_case("TRUE") => FilterLiteral::Bool(true),
+            Some(Token::Ident(kw)) if kw.eq_ignore_ascii_case("FALSE") => {
+                FilterLiteral::Bool(false)
+            }
+            token => {
+                return Err(GeozeroError::FilterExpression(format!(
+                    "expected literal after {name}, found {token:?}"
+                )))
+            }
+        };
+        Ok(FilterExpr::Compare(name, op, literal))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn feature(properties: &[(&str, OwnedColumnValue)]) -> RecordedFeature {
+        RecordedFeature {
+            properties: properties
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let expr: FilterExpr = "name = 'x' AND pop > 5".parse().unwrap();
+        assert_eq!(
+            expr,
+            FilterExpr::And(
+                Box::new(FilterExpr::Compare(
+                    "name".to_string(),
+                    CompareOp::Eq,
+                    FilterLiteral::String("x".to_string())
+                )),
+                Box::new(FilterExpr::Compare(
+                    "pop".to_string(),
+                    CompareOp::Gt,
+                    FilterLiteral::Number(5.0)
+                )),
+            )
+        );
+        let expr: FilterExpr = r#"NOT ("the name" <> 'it''s' or b is not null)"#.parse().unwrap();
+        assert_eq!(
+            expr,
+            FilterExpr::Not(Box::new(FilterExpr::Or(
+                Box::new(FilterExpr::Compare(
+                    "the name".to_string(),
+                    CompareOp::Ne,
+                    FilterLiteral::String("it's".to_string())
+                )),
+                Box::new(FilterExpr::Not(Box::new(FilterExpr::IsNull(
+                    "b".to_string()
+                )))),
+            )))
+        );
+
+        for invalid in [
+            "",
+            "name",
+            "name =",
+            "name = 'x",
+            "(a = 1",
+            "a = 1 b",
+            "a IS 1",
+        ] {
+            assert!(invalid.parse::<FilterExpr>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn eval() {
+        let f = feature(&[
+            ("name", OwnedColumnValue::String("x".to_string())),
+            ("pop", OwnedColumnValue::Int(10)),
+            ("area", OwnedColumnValue::String("2.5".to_string())),
+            ("capital", OwnedColumnValue::Bool(true)),
+            ("empty", OwnedColumnValue::Null),
+        ]);
+        let eval = |expr: &str| expr.parse::<FilterExpr>().unwrap().eval(&f);
+        assert!(eval("name = 'x' AND pop > 5"));
+        assert!(!eval("name = 'x' AND pop > 10"));
+        assert!(eval("pop >= 10 AND pop <= 10.0 AND pop != 9"));
+        assert!(eval("area < 3"));
+        assert!(eval("capital = TRUE"));
+        assert!(eval(
+            "empty IS NULL AND missing IS NULL AND name IS NOT NULL"
+        ));
+        assert!(!eval("empty = 'x'"));
+        assert!(!eval("empty != 'x'"));
+        assert!(eval("name = 'y' OR NOT pop < 0"));
+    }
+
+    #[test]
+    #[cfg(feature = "with-geojson")]
+    fn filter_features() {
+        use crate::geojson::{GeoJsonLineReader, GeoJsonWriter};
+        use crate::GeozeroDatasource;
+
+        let input = r#"{"type": "Feature", "properties": {"name": "a", "pop": 1}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+{"type": "Feature", "properties": {"name": "b", "pop": 10}, "geometry": {"type": "Point", "coordinates": [2, 2]}}
+{"type": "Feature", "properties": {"name": "c", "pop": 20}, "geometry": {"type": "Point", "coordinates": [3, 3]}}"#;
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut filter =
+            FilterProcessor::with_expr(GeoJsonWriter::new(&mut out), "pop > 5 AND name <> 'c'")
+                .unwrap();
+        GeoJsonLineReader::new(input.as_bytes())
+            .process(&mut filter)
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "FeatureCollection",
+                "features": [
+                    {"type": "Feature", "properties": {"name": "b", "pop": 10}, "geometry": {"type": "Point", "coordinates": [2, 2]}}
+                ]
+            })
+        );
+
+        let mut indices = Vec::new();
+        let mut filter =
+            FilterProcessor::new(IdxCollector(&mut indices), |feature: &RecordedFeature| {
+                feature
+                    .property_value("name")
+                    .is_some_and(|name| name.to_string() != "a")
+            });
+        GeoJsonLineReader::new(input.as_bytes())
+            .process(&mut filter)
+            .unwrap();
+        assert_eq!(indices, [0, 1]);
+    }
+
+    struct IdxCollector<'a>(&'a mut Vec<u64>);
+    impl FeatureProcessor for IdxCollector<'_> {
+        fn feature_begin(&mut self, idx: u64) -> Result<()> {
+            self.0.push(idx);
+            Ok(())
+        }
+    }
+    impl GeomProcessor for IdxCollector<'_> {}
+    impl PropertyProcessor for IdxCollector<'_> {}
+}
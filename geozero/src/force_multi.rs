@@ -0,0 +1,161 @@
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::PropertyProcessor;
+
+/// Processor promoting single geometries to multi-geometries
+///
+/// Points, linestrings and polygons are passed on as multipoints, multilinestrings and
+/// multipolygons with a single member, also within geometry collections.
+/// Other geometries are passed unchanged.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::{ForceMulti, GeozeroGeometry};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = ForceMulti::new(WktWriter::new(&mut out));
+/// Wkt("POLYGON((0 0,1 0,1 1,0 0))").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "MULTIPOLYGON(((0 0,1 0,1 1,0 0)))");
+/// # }
+/// ```
+pub struct ForceMulti<P> {
+    inner: P,
+    /// Within a multipoint
+    in_multipoint: bool,
+}
+
+impl<P: GeomProcessor> ForceMulti<P> {
+    pub fn new(inner: P) -> Self {
+        ForceMulti {
+            inner,
+            in_multipoint: false,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for ForceMulti<P> {
+    forward!(
+        inner => dataset_begin, schema, dataset_end, feature_begin, feature_end, properties_begin,
+        properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for ForceMulti<P> {
+    forward!(inner => property);
+}
+
+impl<P: GeomProcessor> GeomProcessor for ForceMulti<P> {
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        if self.in_multipoint {
+            self.inner.empty_point(idx)
+        } else {
+            self.inner.multipoint_begin(0, idx)?;
+            self.inner.multipoint_end(idx)
+        }
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.inner.multipoint_begin(1, idx)
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multipoint_end(idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.in_multipoint = true;
+        self.inner.multipoint_begin(size, idx)
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.in_multipoint = false;
+        self.inner.multipoint_end(idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if tagged {
+            self.inner.multilinestring_begin(1, idx)?;
+            self.inner.linestring_begin(false, size, 0)
+        } else {
+            self.inner.linestring_begin(false, size, idx)
+        }
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if tagged {
+            self.inner.linestring_end(false, 0)?;
+            self.inner.multilinestring_end(idx)
+        } else {
+            self.inner.linestring_end(false, idx)
+        }
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if tagged {
+            self.inner.multipolygon_begin(1, idx)?;
+            self.inner.polygon_begin(false, size, 0)
+        } else {
+            self.inner.polygon_begin(false, size, idx)
+        }
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if tagged {
+            self.inner.polygon_end(false, 0)?;
+            self.inner.multipolygon_end(idx)
+        } else {
+            self.inner.polygon_end(false, idx)
+        }
+    }
+    forward!(
+        inner => dimensions, multi_dim, supports_curves, srid, xy, coordinate,
+        multilinestring_begin, multilinestring_end, multipolygon_begin, multipolygon_end,
+        geometrycollection_begin, geometrycollection_end, circularstring_begin, circularstring_end,
+        compoundcurve_begin, compoundcurve_end, curvepolygon_begin, curvepolygon_end,
+        multicurve_begin, multicurve_end, multisurface_begin, multisurface_end, triangle_begin,
+        triangle_end, polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn force_multi() {
+        use crate::wkt::{Wkt, WktWriter};
+        use crate::GeozeroGeometry;
+
+        let force_multi = |wkt: &str| {
+            let mut out: Vec<u8> = Vec::new();
+            Wkt(wkt)
+                .process_geom(&mut ForceMulti::new(WktWriter::new(&mut out)))
+                .unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(force_multi("POINT(1 2)"), "MULTIPOINT(1 2)");
+        assert_eq!(force_multi("POINT EMPTY"), "MULTIPOINT EMPTY");
+        assert_eq!(
+            force_multi("LINESTRING(0 0,1 1)"),
+            "MULTILINESTRING((0 0,1 1))"
+        );
+        assert_eq!(
+            force_multi("POLYGON((0 0,1 0,1 1,0 0),(0.2 0.1,0.8 0.1,0.8 0.7,0.2 0.1))"),
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0),(0.2 0.1,0.8 0.1,0.8 0.7,0.2 0.1)))"
+        );
+        assert_eq!(
+            force_multi("GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))"),
+            "GEOMETRYCOLLECTION(MULTIPOINT(1 2),MULTILINESTRING((0 0,1 1)))"
+        );
+        for multi in [
+            "MULTIPOINT(1 2,3 4)",
+            "MULTILINESTRING((0 0,1 1),(2 2,3 3))",
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((5 5,6 5,6 6,5 5)))",
+        ] {
+            assert_eq!(force_multi(multi), multi);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Forwarding of processor events to a wrapped processor
+
+/// Implement processor methods by forwarding them unchanged to the field `$inner`
+///
+/// Used in the `FeatureProcessor`, `PropertyProcessor` and `GeomProcessor` impls of
+/// wrapping processors, which then only implement the methods they modify:
+///
+/// ```ignore
+/// impl<P: GeomProcessor> GeomProcessor for Wrapper<P> {
+///     fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+///         self.inner.xy(x * 2.0, y, idx)
+///     }
+///     forward!(inner => dimensions, point_begin, point_end);
+/// }
+/// ```
+macro_rules! forward {
+    ($inner:tt => $($method:ident),+ $(,)?) => {
+        $($crate::forward::forward!(@method $inner $method);)+
+    };
+
+    // FeatureProcessor
+    (@method $inner:tt dataset_begin) => {
+        fn dataset_begin(&mut self, name: Option<&str>) -> $crate::error::Result<()> {
+            self.$inner.dataset_begin(name)
+        }
+    };
+    (@method $inner:tt schema) => {
+        fn schema(&mut self, schema: &$crate::Schema) -> $crate::error::Result<()> {
+            self.$inner.schema(schema)
+        }
+    };
+    (@method $inner:tt dataset_end) => { $crate::forward::forward!(@unit $inner dataset_end); };
+    (@method $inner:tt feature_begin) => { $crate::forward::forward!(@feature $inner feature_begin); };
+    (@method $inner:tt feature_end) => { $crate::forward::forward!(@feature $inner feature_end); };
+    (@method $inner:tt properties_begin) => { $crate::forward::forward!(@unit $inner properties_begin); };
+    (@method $inner:tt properties_end) => { $crate::forward::forward!(@unit $inner properties_end); };
+    (@method $inner:tt geometry_begin) => { $crate::forward::forward!(@unit $inner geometry_begin); };
+    (@method $inner:tt geometry_end) => { $crate::forward::forward!(@unit $inner geometry_end); };
+
+    // PropertyProcessor
+    (@method $inner:tt property) => {
+        fn property(
+            &mut self,
+            idx: usize,
+            name: &str,
+            value: &$crate::ColumnValue,
+        ) -> $crate::error::Result<bool> {
+            self.$inner.property(idx, name, value)
+        }
+    };
+
+    // GeomProcessor
+    (@method $inner:tt dimensions) => {
+        fn dimensions(&self) -> $crate::CoordDimensions {
+            self.$inner.dimensions()
+        }
+    };
+    (@method $inner:tt multi_dim) => {
+        fn multi_dim(&self) -> bool {
+            self.$inner.multi_dim()
+        }
+    };
+    (@method $inner:tt supports_curves) => {
+        fn supports_curves(&self) -> bool {
+            self.$inner.supports_curves()
+        }
+    };
+    (@method $inner:tt srid) => {
+        fn srid(&mut self, srid: Option<i32>) -> $crate::error::Result<()> {
+            self.$inner.srid(srid)
+        }
+    };
+    (@method $inner:tt xy) => {
+        fn xy(&mut self, x: f64, y: f64, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.xy(x, y, idx)
+        }
+    };
+    (@method $inner:tt coordinate) => {
+        fn coordinate(
+            &mut self,
+            x: f64,
+            y: f64,
+            z: Option<f64>,
+            m: Option<f64>,
+            t: Option<f64>,
+            tm: Option<u64>,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            self.$inner.coordinate(x, y, z, m, t, tm, idx)
+        }
+    };
+    (@method $inner:tt empty_point) => { $crate::forward::forward!(@idx $inner empty_point); };
+    (@method $inner:tt point_begin) => { $crate::forward::forward!(@idx $inner point_begin); };
+    (@method $inner:tt point_end) => { $crate::forward::forward!(@idx $inner point_end); };
+    (@method $inner:tt multipoint_begin) => { $crate::forward::forward!(@begin $inner multipoint_begin); };
+    (@method $inner:tt multipoint_end) => { $crate::forward::forward!(@idx $inner multipoint_end); };
+    (@method $inner:tt linestring_begin) => { $crate::forward::forward!(@tagged_begin $inner linestring_begin); };
+    (@method $inner:tt linestring_end) => { $crate::forward::forward!(@tagged_end $inner linestring_end); };
+    (@method $inner:tt multilinestring_begin) => { $crate::forward::forward!(@begin $inner multilinestring_begin); };
+    (@method $inner:tt multilinestring_end) => { $crate::forward::forward!(@idx $inner multilinestring_end); };
+    (@method $inner:tt polygon_begin) => { $crate::forward::forward!(@tagged_begin $inner polygon_begin); };
+    (@method $inner:tt polygon_end) => { $crate::forward::forward!(@tagged_end $inner polygon_end); };
+    (@method $inner:tt multipolygon_begin) => { $crate::forward::forward!(@begin $inner multipolygon_begin); };
+    (@method $inner:tt multipolygon_end) => { $crate::forward::forward!(@idx $inner multipolygon_end); };
+    (@method $inner:tt geometrycollection_begin) => { $crate::forward::forward!(@begin $inner geometrycollection_begin); };
+    (@method $inner:tt geometrycollection_end) => { $crate::forward::forward!(@idx $inner geometrycollection_end); };
+    (@method $inner:tt circularstring_begin) => { $crate::forward::forward!(@begin $inner circularstring_begin); };
+    (@method $inner:tt circularstring_end) => { $crate::forward::forward!(@idx $inner circularstring_end); };
+    (@method $inner:tt compoundcurve_begin) => { $crate::forward::forward!(@begin $inner compoundcurve_begin); };
+    (@method $inner:tt compoundcurve_end) => { $crate::forward::forward!(@idx $inner compoundcurve_end); };
+    (@method $inner:tt curvepolygon_begin) => { $crate::forward::forward!(@begin $inner curvepolygon_begin); };
+    (@method $inner:tt curvepolygon_end) => { $crate::forward::forward!(@idx $inner curvepolygon_end); };
+    (@method $inner:tt multicurve_begin) => { $crate::forward::forward!(@begin $inner multicurve_begin); };
+    (@method $inner:tt multicurve_end) => { $crate::forward::forward!(@idx $inner multicurve_end); };
+    (@method $inner:tt multisurface_begin) => { $crate::forward::forward!(@begin $inner multisurface_begin); };
+    (@method $inner:tt multisurface_end) => { $crate::forward::forward!(@idx $inner multisurface_end); };
+    (@method $inner:tt triangle_begin) => { $crate::forward::forward!(@tagged_begin $inner triangle_begin); };
+    (@method $inner:tt triangle_end) => { $crate::forward::forward!(@tagged_end $inner triangle_end); };
+    (@method $inner:tt polyhedralsurface_begin) => { $crate::forward::forward!(@begin $inner polyhedralsurface_begin); };
+    (@method $inner:tt polyhedralsurface_end) => { $crate::forward::forward!(@idx $inner polyhedralsurface_end); };
+    (@method $inner:tt tin_begin) => { $crate::forward::forward!(@begin $inner tin_begin); };
+    (@method $inner:tt tin_end) => { $crate::forward::forward!(@idx $inner tin_end); };
+
+    // Method signatures shared by several events
+    (@unit $inner:tt $method:ident) => {
+        fn $method(&mut self) -> $crate::error::Result<()> {
+            self.$inner.$method()
+        }
+    };
+    (@feature $inner:tt $method:ident) => {
+        fn $method(&mut self, idx: u64) -> $crate::error::Result<()> {
+            self.$inner.$method(idx)
+        }
+    };
+    (@idx $inner:tt $method:ident) => {
+        fn $method(&mut self, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.$method(idx)
+        }
+    };
+    (@begin $inner:tt $method:ident) => {
+        fn $method(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.$method(size, idx)
+        }
+    };
+    (@tagged_begin $inner:tt $method:ident) => {
+        fn $method(&mut self, tagged: bool, size: usize, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.$method(tagged, size, idx)
+        }
+    };
+    (@tagged_end $inner:tt $method:ident) => {
+        fn $method(&mut self, tagged: bool, idx: usize) -> $crate::error::Result<()> {
+            self.$inner.$method(tagged, idx)
+        }
+    };
+}
+
+pub(crate) use forward;
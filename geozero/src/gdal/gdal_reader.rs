@@ -1,5 +1,5 @@
 use crate::error::{GeozeroError, Result};
-use crate::{GeomProcessor, GeozeroGeometry};
+use crate::{ArcTolerance, GeomProcessor, GeozeroGeometry, LinearizeProcessor, ProcessorRef};
 use gdal::vector::Geometry;
 use gdal_sys::{self, OGRwkbGeometryType};
 
@@ -11,7 +11,13 @@ impl GeozeroGeometry for Geometry {
 
 /// Process GDAL/OGR geometry.
 pub fn process_geom<P: GeomProcessor>(geo: &Geometry, processor: &mut P) -> Result<()> {
-    process_geom_n(geo, 0, processor)
+    if processor.supports_curves() {
+        process_geom_n(geo, 0, processor)
+    } else {
+        let mut linearize =
+            LinearizeProcessor::new(ProcessorRef(processor), ArcTolerance::default());
+        process_geom_n(geo, 0, &mut linearize)
+    }
 }
 
 fn process_geom_n<P: GeomProcessor>(geo: &Geometry, idx: usize, processor: &mut P) -> Result<()> {
@@ -65,6 +65,9 @@ impl GeomProcessor for GdalWriter {
     fn dimensions(&self) -> CoordDimensions {
         self.dims
     }
+    fn supports_curves(&self) -> bool {
+        false
+    }
     fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
         match self.geom.geometry_type() {
             OGRwkbGeometryType::wkbPoint | OGRwkbGeometryType::wkbLineString => {
@@ -49,6 +49,9 @@ impl GeoWriter {
 }
 
 impl GeomProcessor for GeoWriter {
+    fn supports_curves(&self) -> bool {
+        false
+    }
     fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
         let coords = self
             .coords
@@ -1,6 +1,7 @@
 use crate::{
-    error::{GeozeroError, Result},
-    FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry,
+    error::{ErrorPolicy, GeozeroError, Result, SkippedFeature},
+    FeatureIter, FeatureProcessor, FeatureSource, GeomProcessor, GeozeroDatasource,
+    GeozeroGeometry,
 };
 
 use super::{process_geojson_geom_n, process_properties};
@@ -12,10 +13,40 @@ use geojson::{Feature, GeoJson as GeoGeoJson, Geometry};
 /// Line Delimited GeoJSON Reader: One feature per line.
 ///
 /// See <https://jsonlines.org>
-pub struct GeoJsonLineReader<R: Read>(pub(crate) R);
-impl<R: Read> GeoJsonLineReader<R> {
+pub struct GeoJsonLineReader<R> {
+    inner: R,
+    error_policy: ErrorPolicy,
+    skipped: Vec<SkippedFeature>,
+}
+
+impl<R> GeoJsonLineReader<R> {
     pub fn new(read: R) -> Self {
-        Self(read)
+        Self {
+            inner: read,
+            error_policy: ErrorPolicy::Abort,
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Handle invalid lines according to `error_policy`
+    ///
+    /// Applies to records failing to parse when processing the reader, not to iterating with
+    /// `features()`.
+    pub fn with_error_policy(mut self, error_policy: ErrorPolicy) -> Self {
+        self.error_policy = error_policy;
+        self
+    }
+
+    /// Lines skipped by the last `process` call
+    pub fn skipped(&self) -> &[SkippedFeature] {
+        &self.skipped
+    }
+}
+
+impl<R: Read> GeoJsonLineReader<R> {
+    /// Iterate over owned features
+    pub fn features(self) -> FeatureIter<GeoJsonLineReader<BufReader<R>>> {
+        FeatureIter::new(GeoJsonLineReader::new(BufReader::new(self.inner)))
     }
 }
 
@@ -24,13 +55,44 @@ impl<R: Read + Clone> GeozeroGeometry for GeoJsonLineReader<R> {
     where
         Self: Sized,
     {
-        read_geojson_line_geometries(&mut self.0.clone(), processor)
+        read_geojson_line_geometries(&mut self.inner.clone(), processor)
     }
 }
 
 impl<R: Read> GeozeroDatasource for GeoJsonLineReader<R> {
     fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
-        read_geojson_lines(&mut self.0, processor)
+        self.skipped =
+            read_geojson_lines_with_policy(&mut self.inner, processor, self.error_policy)?;
+        Ok(())
+    }
+}
+
+impl<R: BufRead> FeatureSource for GeoJsonLineReader<R> {
+    fn process_next_feature<P: FeatureProcessor>(
+        &mut self,
+        idx: u64,
+        processor: &mut P,
+    ) -> Result<bool> {
+        let mut line = String::new();
+        if self.inner.read_line(&mut line)? == 0 {
+            return Ok(false);
+        }
+        let object = parse_line(line.trim_end_matches(['\r', '\n']))?;
+        process_object(processor, idx as usize, &object)?;
+        Ok(true)
+    }
+}
+
+#[cfg(feature = "with-tokio")]
+#[async_trait::async_trait]
+impl<R: tokio::io::AsyncRead + Unpin + Send> crate::AsyncGeozeroDatasource
+    for GeoJsonLineReader<R>
+{
+    async fn process<P: FeatureProcessor + Send>(&mut self, processor: &mut P) -> Result<()> {
+        self.skipped =
+            read_geojson_lines_async_with_policy(&mut self.inner, processor, self.error_policy)
+                .await?;
+        Ok(())
     }
 }
 
@@ -81,19 +143,103 @@ pub fn read_geojson_line_geometries(
 
 /// Read and process line delimited GeoJSON (one object per line).
 pub fn read_geojson_lines(reader: impl Read, processor: &mut impl FeatureProcessor) -> Result<()> {
+    read_geojson_lines_with_policy(reader, processor, ErrorPolicy::Abort)?;
+    Ok(())
+}
+
+/// Read and process line delimited GeoJSON (one object per line), handling invalid lines
+/// according to `policy`.
+///
+/// Returns the skipped lines. Processed objects are numbered consecutively.
+pub fn read_geojson_lines_with_policy(
+    reader: impl Read,
+    processor: &mut impl FeatureProcessor,
+    policy: ErrorPolicy,
+) -> Result<Vec<SkippedFeature>> {
     let buf_reader = BufReader::new(reader);
 
     processor.dataset_begin(None)?;
-    for (idx, line) in buf_reader.lines().enumerate() {
-        match line?.parse::<GeoGeoJson>()? {
-            GeoGeoJson::Feature(feature) => process_feature(processor, idx, &feature)?,
-            GeoGeoJson::Geometry(geometry) => process_geometry(processor, idx, &geometry)?,
-            _ => {
-                return Err(GeozeroError::Dataset("line-delimited GeoJson ('geojsonl') files must have one Feature or Geometry per line".to_string()));
+    let mut skipped = Vec::new();
+    let mut idx = 0;
+    for (line_idx, line) in buf_reader.lines().enumerate() {
+        match line
+            .map_err(GeozeroError::from)
+            .and_then(|line| parse_line(&line))
+        {
+            Ok(object) => {
+                process_object(processor, idx, &object)?;
+                idx += 1;
             }
+            Err(e) => policy.skip(e, line_idx as u64, Some(line_idx as u64 + 1), &mut skipped)?,
         }
     }
-    processor.dataset_end()
+    processor.dataset_end()?;
+    Ok(skipped)
+}
+
+/// Read and process line delimited GeoJSON (one object per line) from an async reader.
+#[cfg(feature = "with-tokio")]
+pub async fn read_geojson_lines_async(
+    reader: impl tokio::io::AsyncRead + Unpin,
+    processor: &mut impl FeatureProcessor,
+) -> Result<()> {
+    read_geojson_lines_async_with_policy(reader, processor, ErrorPolicy::Abort).await?;
+    Ok(())
+}
+
+/// Read and process line delimited GeoJSON (one object per line) from an async reader,
+/// handling invalid lines according to `policy`.
+///
+/// Returns the skipped lines. Processed objects are numbered consecutively.
+#[cfg(feature = "with-tokio")]
+pub async fn read_geojson_lines_async_with_policy(
+    reader: impl tokio::io::AsyncRead + Unpin,
+    processor: &mut impl FeatureProcessor,
+    policy: ErrorPolicy,
+) -> Result<Vec<SkippedFeature>> {
+    use tokio::io::AsyncBufReadExt;
+
+    let mut lines = tokio::io::BufReader::new(reader).lines();
+
+    processor.dataset_begin(None)?;
+    let mut skipped = Vec::new();
+    let mut idx = 0;
+    let mut line_idx = 0;
+    while let Some(line) = lines.next_line().await? {
+        match parse_line(&line) {
+            Ok(object) => {
+                process_object(processor, idx, &object)?;
+                idx += 1;
+            }
+            Err(e) => policy.skip(e, line_idx, Some(line_idx + 1), &mut skipped)?,
+        }
+        line_idx += 1;
+    }
+    processor.dataset_end()?;
+    Ok(skipped)
+}
+
+/// Parse a line into a Feature or Geometry
+fn parse_line(line: &str) -> Result<GeoGeoJson> {
+    match line.parse::<GeoGeoJson>()? {
+        GeoGeoJson::FeatureCollection(_) => Err(GeozeroError::Dataset(
+            "line-delimited GeoJson ('geojsonl') files must have one Feature or Geometry per line"
+                .to_string(),
+        )),
+        object => Ok(object),
+    }
+}
+
+fn process_object(
+    processor: &mut impl FeatureProcessor,
+    idx: usize,
+    object: &GeoGeoJson,
+) -> Result<()> {
+    match object {
+        GeoGeoJson::Feature(feature) => process_feature(processor, idx, feature),
+        GeoGeoJson::Geometry(geometry) => process_geometry(processor, idx, geometry),
+        GeoGeoJson::FeatureCollection(_) => unreachable!("rejected by parse_line"),
+    }
 }
 
 fn process_feature(
@@ -137,7 +283,7 @@ mod tests {
 { "type": "Point", "coordinates": [2.1, 2.2] }
 { "type": "Point", "coordinates": [3.1, 3.2] }
 "#;
-        let reader = GeoJsonLineReader(input.as_bytes());
+        let reader = GeoJsonLineReader::new(input.as_bytes());
         let wkt = reader.to_wkt().unwrap();
         let expected = "GEOMETRYCOLLECTION(POINT(1.1 1.2),POINT(2.1 2.2),POINT(3.1 3.2))";
         assert_eq!(wkt, expected);
@@ -149,7 +295,7 @@ mod tests {
 { "type": "Feature", "geometry": { "type": "Point", "coordinates": [2.1, 2.2] }, "properties": { "name": "second" } }
 { "type": "Feature", "geometry": { "type": "Point", "coordinates": [3.1, 3.3] }, "properties": { "name": "third" } }
 "#;
-        let mut reader = GeoJsonLineReader(input.as_bytes());
+        let mut reader = GeoJsonLineReader::new(input.as_bytes());
         let json_string = reader.to_json().unwrap();
         let json: serde_json::Value = serde_json::from_str(&json_string)
             .unwrap_or_else(|err| panic!("invalid json: `{json_string}`: {err}"));
@@ -163,15 +309,66 @@ mod tests {
         assert_eq!(json, expected);
     }
 
+    #[test]
+    fn feature_iter() {
+        use crate::OwnedColumnValue;
+
+        let input = r#"{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [1.1, 1.2] }, "properties": { "name": "first" } }
+{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [2.1, 2.2] }, "properties": { "name": "second" } }
+ooops this is malformed json
+"#;
+        let mut features = GeoJsonLineReader::new(input.as_bytes()).features();
+        let first = features.next().unwrap().unwrap();
+        assert_eq!(first.idx, 0);
+        assert_eq!(
+            first.properties,
+            vec![(
+                "name".to_string(),
+                OwnedColumnValue::String("first".to_string())
+            )]
+        );
+        assert_eq!(first.to_wkt().unwrap(), "POINT(1.1 1.2)");
+        let second = features.next().unwrap().unwrap();
+        assert_eq!(second.idx, 1);
+        assert_eq!(second.to_wkt().unwrap(), "POINT(2.1 2.2)");
+        assert!(features.next().unwrap().is_err());
+        assert!(features.next().is_none());
+    }
+
     #[test]
     fn malformed_json() {
         let input = r#"{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [1.1, 1.2] }, "properties": { "name": "first" } }
 ooops this is malformed json { "type": "Feature", "geometry": { "type": "Point", "coordinates": [2.1, 2.2] }, "properties": { "name": "second" } }
 { "type": "Feature", "geometry": { "type": "Point", "coordinates": [3.1, 3.3] }, "properties": { "name": "third" } }
 "#;
-        let mut reader = GeoJsonLineReader(input.as_bytes());
+        let mut reader = GeoJsonLineReader::new(input.as_bytes());
         _ = reader.to_json().unwrap_err();
     }
+    #[test]
+    fn error_policy() {
+        let input = r#"{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [1.1, 1.2] }, "properties": { "name": "first" } }
+ooops this is malformed json
+{ "type": "FeatureCollection", "features": [] }
+{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [3.1, 3.3] }, "properties": { "name": "third" } }
+"#;
+        let mut reader =
+            GeoJsonLineReader::new(input.as_bytes()).with_error_policy(ErrorPolicy::Collect);
+        let json: serde_json::Value = serde_json::from_str(&reader.to_json().unwrap()).unwrap();
+        let expected = serde_json::json!({
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {"name": "first"}, "geometry": {"type": "Point", "coordinates": [1.1,1.2]}},
+                {"type": "Feature", "properties": {"name": "third"}, "geometry": {"type": "Point", "coordinates": [3.1,3.3]}}]
+        });
+        assert_eq!(json, expected);
+        let skipped = reader.skipped();
+        assert_eq!(
+            skipped.iter().map(|s| (s.idx, s.line)).collect::<Vec<_>>(),
+            vec![(1, Some(2)), (2, Some(3))]
+        );
+        assert!(skipped.iter().all(|s| s.error.is_some()));
+    }
+
     #[test]
     fn valid_json_but_not_one_feature_per_line() {
         let input = r#"{
@@ -182,7 +379,31 @@ ooops this is malformed json { "type": "Feature", "geometry": { "type": "Point",
             }, "
             properties": { "name": "first" }
         }"#;
-        let mut reader = GeoJsonLineReader(input.as_bytes());
+        let mut reader = GeoJsonLineReader::new(input.as_bytes());
         _ = reader.to_json().unwrap_err();
     }
+
+    #[cfg(feature = "with-tokio")]
+    #[tokio::test]
+    async fn async_features() {
+        use crate::geojson::GeoJsonWriter;
+        use crate::AsyncGeozeroDatasource;
+
+        let input = r#"{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [1.1, 1.2] }, "properties": { "name": "first" } }
+{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [2.1, 2.2] }, "properties": { "name": "second" } }
+"#;
+        let mut reader = GeoJsonLineReader::new(input.as_bytes());
+        let mut out: Vec<u8> = Vec::new();
+        AsyncGeozeroDatasource::process(&mut reader, &mut GeoJsonWriter::new(&mut out))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let expected = serde_json::json!({
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {"name": "first"}, "geometry": {"type": "Point", "coordinates": [1.1,1.2]}},
+                {"type": "Feature", "properties": {"name": "second"}, "geometry": {"type": "Point", "coordinates": [2.1,2.2]}}]
+        });
+        assert_eq!(json, expected);
+    }
 }
@@ -2,7 +2,7 @@ use std::io::Write;
 
 use crate::error::Result;
 use crate::geojson::GeoJsonWriter;
-use crate::{FeatureProcessor, GeomProcessor, PropertyProcessor};
+use crate::{CoordPrecision, FeatureProcessor, GeomProcessor, PropertyProcessor};
 
 /// Line Delimited GeoJSON Writer: One feature per line.
 ///
@@ -29,6 +29,18 @@ impl<W: Write> GeoJsonLineWriter<W> {
         }
     }
 
+    /// Write coordinates with the given precision
+    pub fn with_precision(mut self, precision: CoordPrecision) -> Self {
+        self.line_writer = self.line_writer.with_precision(precision);
+        self
+    }
+
+    /// Write polygon rings in RFC 7946 winding order, with counter-clockwise exterior rings
+    pub fn with_rfc7946(mut self, rfc7946: bool) -> Self {
+        self.line_writer = self.line_writer.with_rfc7946(rfc7946);
+        self
+    }
+
     fn write_newline(&mut self) -> Result<()> {
         self.line_writer.out.write_all(b"\n")?;
         Ok(())
@@ -89,6 +101,10 @@ impl<W: Write> GeomProcessor for GeoJsonLineWriter<W> {
         self.line_writer.dimensions()
     }
 
+    fn supports_curves(&self) -> bool {
+        self.line_writer.supports_curves()
+    }
+
     fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
         self.line_writer.xy(x, y, idx)
     }
@@ -217,7 +217,8 @@ pub(crate) fn process_properties<P: PropertyProcessor>(
                 processor.property(i, key, &ColumnValue::ULong(v.as_u64().unwrap()))?
             }
             JsonValue::Bool(v) => processor.property(i, key, &ColumnValue::Bool(*v))?,
-            // Null, Array(Vec<Value>), Object(Map<String, Value>)
+            JsonValue::Null => processor.property(i, key, &ColumnValue::Null)?,
+            // Array(Vec<Value>), Object(Map<String, Value>)
             _ => processor.property(i, key, &ColumnValue::String(&value.to_string()))?,
         };
     }
@@ -355,7 +356,8 @@ mod test {
                 "type": "Feature",
                 "properties": {
                     "id": 1,
-                    "name": "New Zealand"
+                    "name": "New Zealand",
+                    "population": null
                 },
                 "geometry": {
                     "type": "Point",
@@ -369,7 +371,7 @@ mod test {
             std::str::from_utf8(&out).unwrap(),
             r#"{
 "type": "FeatureCollection",
-"features": [{"type": "Feature", "properties": {"id": 1, "name": "New Zealand"}, "geometry": {"type": "Point", "coordinates": [10,20]}}]}"#
+"features": [{"type": "Feature", "properties": {"id": 1, "name": "New Zealand", "population": null}, "geometry": {"type": "Point", "coordinates": [10,20]}}]}"#
         );
 
         assert_eq!(
@@ -1,23 +1,46 @@
 use crate::error::Result;
-use crate::{ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
+use crate::orient::orient_ring;
+use crate::{
+    ColumnValue, CoordDimensions, CoordPrecision, FeatureProcessor, GeomProcessor,
+    PropertyProcessor, Winding,
+};
 use std::fmt::Display;
 use std::io::Write;
 
 /// GeoJSON writer.
 pub struct GeoJsonWriter<W: Write> {
     dims: CoordDimensions,
+    precision: CoordPrecision,
+    rfc7946: bool,
+    in_polygon: bool,
+    /// Coordinates of the current polygon ring in RFC 7946 mode
+    ring: Option<Vec<(f64, f64, Option<f64>)>>,
     pub(crate) out: W,
 }
 
 impl<W: Write> GeoJsonWriter<W> {
     pub fn new(out: W) -> Self {
+        Self::with_dims(out, CoordDimensions::default())
+    }
+    pub fn with_dims(out: W, dims: CoordDimensions) -> Self {
         GeoJsonWriter {
-            dims: CoordDimensions::default(),
+            dims,
+            precision: CoordPrecision::default(),
+            rfc7946: false,
+            in_polygon: false,
+            ring: None,
             out,
         }
     }
-    pub fn with_dims(out: W, dims: CoordDimensions) -> Self {
-        GeoJsonWriter { dims, out }
+    /// Write coordinates with the given precision
+    pub fn with_precision(mut self, precision: CoordPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+    /// Write polygon rings in RFC 7946 winding order, with counter-clockwise exterior rings
+    pub fn with_rfc7946(mut self, rfc7946: bool) -> Self {
+        self.rfc7946 = rfc7946;
+        self
     }
     fn comma(&mut self, idx: usize) -> Result<()> {
         if idx > 0 {
@@ -25,6 +48,17 @@ impl<W: Write> GeoJsonWriter<W> {
         }
         Ok(())
     }
+    fn write_coord(&mut self, x: f64, y: f64, z: Option<f64>, idx: usize) -> Result<()> {
+        self.comma(idx)?;
+        let (x, y) = (self.precision.format(x), self.precision.format(y));
+        self.out.write_all(format!("[{x},{y}").as_bytes())?;
+        if let Some(z) = z {
+            let z = self.precision.format(z);
+            self.out.write_all(format!(",{z}").as_bytes())?;
+        }
+        self.out.write_all(b"]")?;
+        Ok(())
+    }
 }
 
 impl<W: Write> FeatureProcessor for GeoJsonWriter<W> {
@@ -78,10 +112,11 @@ impl<W: Write> GeomProcessor for GeoJsonWriter<W> {
     fn dimensions(&self) -> CoordDimensions {
         self.dims
     }
+    fn supports_curves(&self) -> bool {
+        false
+    }
     fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
-        self.comma(idx)?;
-        self.out.write_all(format!("[{x},{y}]").as_bytes())?;
-        Ok(())
+        self.coordinate(x, y, None, None, None, None, idx)
     }
     fn coordinate(
         &mut self,
@@ -93,13 +128,13 @@ impl<W: Write> GeomProcessor for GeoJsonWriter<W> {
         _tm: Option<u64>,
         idx: usize,
     ) -> Result<()> {
-        self.comma(idx)?;
-        self.out.write_all(format!("[{x},{y}").as_bytes())?;
-        if let Some(z) = z {
-            self.out.write_all(format!(",{z}").as_bytes())?;
+        match &mut self.ring {
+            Some(ring) => {
+                ring.push((x, y, z));
+                Ok(())
+            }
+            None => self.write_coord(x, y, z, idx),
         }
-        self.out.write_all(b"]")?;
-        Ok(())
     }
     fn empty_point(&mut self, idx: usize) -> Result<()> {
         self.comma(idx)?;
@@ -127,7 +162,10 @@ impl<W: Write> GeomProcessor for GeoJsonWriter<W> {
         self.out.write_all(b"]}")?;
         Ok(())
     }
-    fn linestring_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> Result<()> {
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if self.rfc7946 && self.in_polygon && !tagged {
+            self.ring = Some(Vec::with_capacity(size));
+        }
         self.comma(idx)?;
         if tagged {
             self.out
@@ -137,7 +175,13 @@ impl<W: Write> GeomProcessor for GeoJsonWriter<W> {
         }
         Ok(())
     }
-    fn linestring_end(&mut self, tagged: bool, _idx: usize) -> Result<()> {
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if let Some(mut ring) = self.ring.take() {
+            orient_ring(&mut ring, idx, Winding::CounterClockwise, |c| (c.0, c.1));
+            for (i, (x, y, z)) in ring.into_iter().enumerate() {
+                self.write_coord(x, y, z, i)?;
+            }
+        }
         if tagged {
             self.out.write_all(b"]}")?;
         } else {
@@ -156,6 +200,7 @@ impl<W: Write> GeomProcessor for GeoJsonWriter<W> {
         Ok(())
     }
     fn polygon_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
         self.comma(idx)?;
         if tagged {
             self.out
@@ -166,6 +211,7 @@ impl<W: Write> GeomProcessor for GeoJsonWriter<W> {
         Ok(())
     }
     fn polygon_end(&mut self, tagged: bool, _idx: usize) -> Result<()> {
+        self.in_polygon = false;
         if tagged {
             self.out.write_all(b"]}")?;
         } else {
@@ -230,6 +276,7 @@ impl<W: Write> PropertyProcessor for GeoJsonWriter<W> {
             }
             ColumnValue::Json(_v) => (),
             ColumnValue::Binary(_v) => (),
+            ColumnValue::Null => write_num_prop(&mut self.out, colname, &"null")?,
         };
         Ok(false)
     }
@@ -438,6 +485,45 @@ mod test {
         )
     }
 
+    #[test]
+    fn precision() {
+        use crate::{CoordPrecision, GeozeroGeometry};
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = GeoJsonWriter::new(&mut out).with_precision(CoordPrecision::trimmed(3));
+        Wkt("POINT(8.530000000000001 47.12345)")
+            .process_geom(&mut writer)
+            .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            r#"{"type": "Point", "coordinates": [8.53,47.123]}"#
+        );
+    }
+
+    #[test]
+    fn rfc7946_winding() {
+        use crate::GeozeroGeometry;
+
+        let wkt = "POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,4 2,4 4,2 4,2 2))";
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = GeoJsonWriter::new(&mut out).with_rfc7946(true);
+        Wkt(wkt).process_geom(&mut writer).unwrap();
+        assert_json_eq(
+            &out,
+            r#"{"type": "Polygon", "coordinates": [[[0,0],[10,0],[10,10],[0,10],[0,0]],[[2,2],[2,4],[4,4],[4,2],[2,2]]]}"#,
+        );
+
+        // Rings are written as-is by default
+        let mut out: Vec<u8> = Vec::new();
+        Wkt(wkt)
+            .process_geom(&mut GeoJsonWriter::new(&mut out))
+            .unwrap();
+        assert_json_eq(
+            &out,
+            r#"{"type": "Polygon", "coordinates": [[[0,0],[0,10],[10,10],[10,0],[0,0]],[[2,2],[4,2],[4,4],[2,4],[2,2]]]}"#,
+        );
+    }
+
     fn assert_json_eq(a: &[u8], b: &str) {
         let a = std::str::from_utf8(a).unwrap();
         let a: serde_json::Value = serde_json::from_str(a).unwrap();
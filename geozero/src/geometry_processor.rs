@@ -1,7 +1,7 @@
 use crate::error::{GeozeroError, Result};
 
 /// Dimensions requested for processing
-#[derive(Default, Clone, Copy)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct CoordDimensions {
     /// height
     pub z: bool,
@@ -76,6 +76,14 @@ pub trait GeomProcessor {
         dimensions.z || dimensions.m || dimensions.t || dimensions.tm
     }
 
+    /// Curve geometries are processed
+    ///
+    /// Readers emitting curves approximate them with a [`LinearizeProcessor`](crate::LinearizeProcessor)
+    /// if the processor returns `false`.
+    fn supports_curves(&self) -> bool {
+        true
+    }
+
     /// SRID of geometries
     ///
     /// Emitted before geometry begin
@@ -38,6 +38,9 @@ impl<'a> Default for GeosWriter<'a> {
 }
 
 impl GeomProcessor for GeosWriter<'_> {
+    fn supports_curves(&self) -> bool {
+        false
+    }
     fn srid(&mut self, srid: Option<i32>) -> Result<()> {
         self.srid = srid;
         Ok(())
@@ -11,7 +11,7 @@ impl<'a> crate::GeozeroGeometry for Gpx<'a> {
 }
 
 /// GPX reader
-pub struct GpxReader<R: io::Read>(pub R);
+pub struct GpxReader<R>(pub R);
 
 impl<R: io::Read> crate::GeozeroDatasource for GpxReader<R> {
     fn process<P: crate::FeatureProcessor>(
@@ -22,6 +22,22 @@ impl<R: io::Read> crate::GeozeroDatasource for GpxReader<R> {
     }
 }
 
+/// Reads the whole input before parsing, it is not streamed.
+#[cfg(feature = "with-tokio")]
+#[async_trait::async_trait]
+impl<R: tokio::io::AsyncRead + Unpin + Send> crate::AsyncGeozeroDatasource for GpxReader<R> {
+    async fn process<P: crate::FeatureProcessor + Send>(
+        &mut self,
+        processor: &mut P,
+    ) -> crate::error::Result<()> {
+        use tokio::io::AsyncReadExt;
+        // GPX is a single XML document, which has to be read completely before parsing
+        let mut buf = Vec::new();
+        self.0.read_to_end(&mut buf).await?;
+        read_gpx(&mut buf.as_slice(), processor)
+    }
+}
+
 pub fn read_gpx<R: io::Read, P: crate::GeomProcessor>(
     reader: &mut R,
     processor: &mut P,
@@ -48,18 +48,63 @@
     clippy::struct_excessive_bools
 )]
 
+mod antimeridian;
 mod api;
+mod bbox;
+mod clip;
+mod coord;
+mod densify;
+mod dimensions;
 pub mod error;
+mod explode;
+mod feature_iter;
 mod feature_processor;
+mod filter;
+mod force_multi;
+mod forward;
 mod geometry_processor;
+mod linearize;
+pub mod mercator;
 mod multiplex;
+mod orient;
+mod precision;
 mod property_processor;
-
+mod quantize;
+mod recorded;
+mod schema;
+mod simplify;
+mod summary;
+mod tee;
+mod tracer;
+mod transform;
+mod validator;
+
+pub use antimeridian::*;
 pub use api::*;
+pub use bbox::*;
+pub use clip::*;
+pub use densify::*;
+pub use dimensions::*;
+pub use explode::*;
+pub use feature_iter::*;
 pub use feature_processor::*;
+pub use filter::*;
+pub use force_multi::*;
 pub use geometry_processor::*;
+pub use linearize::*;
 pub use multiplex::*;
+pub use orient::*;
+pub use precision::*;
 pub use property_processor::*;
+pub use quantize::*;
+pub use recorded::*;
+pub use schema::*;
+pub use simplify::*;
+pub use summary::*;
+pub use tee::*;
+pub use tracer::*;
+pub use transform::*;
+pub use validator::*;
 
 #[cfg(feature = "with-csv")]
 pub mod csv;
@@ -99,6 +144,9 @@ pub mod gpx;
 ))]
 pub mod postgis;
 
+#[cfg(feature = "with-proj")]
+pub mod proj;
+
 #[cfg(feature = "with-svg")]
 pub mod svg;
 #[cfg(feature = "with-svg")]
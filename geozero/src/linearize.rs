@@ -0,0 +1,481 @@
+use crate::coord::Coord;
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::PropertyProcessor;
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+/// Accuracy of circular arcs approximated by a [`LinearizeProcessor`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArcTolerance {
+    /// Maximum distance between the arc and its approximating segments
+    MaxDeviation(f64),
+    /// Number of segments per quarter circle
+    SegmentsPerQuadrant(u32),
+}
+
+impl Default for ArcTolerance {
+    /// 32 segments per quarter circle, like PostGIS `ST_CurveToLine`
+    fn default() -> Self {
+        ArcTolerance::SegmentsPerQuadrant(32)
+    }
+}
+
+impl ArcTolerance {
+    /// Maximum angle covered by a single segment
+    fn max_step(&self, radius: f64) -> f64 {
+        match *self {
+            ArcTolerance::MaxDeviation(deviation) if deviation < radius => {
+                2.0 * (1.0 - deviation / radius).acos()
+            }
+            ArcTolerance::MaxDeviation(_) => PI,
+            ArcTolerance::SegmentsPerQuadrant(segments) => FRAC_PI_2 / f64::from(segments.max(1)),
+        }
+    }
+}
+
+/// Point at `angle` on the circle, with other ordinates interpolated between `a` and `b`
+fn arc_point(center: (f64, f64), radius: f64, angle: f64, a: &Coord, b: &Coord, f: f64) -> Coord {
+    Coord {
+        x: center.0 + radius * angle.cos(),
+        y: center.1 + radius * angle.sin(),
+        ..a.interpolate(b, f)
+    }
+}
+
+/// Points approximating the arc from `p0` through `p1` to `p2`, without `p0`
+fn linearize_arc(p0: &Coord, p1: &Coord, p2: &Coord, tolerance: &ArcTolerance) -> Vec<Coord> {
+    let closed = p0.x == p2.x && p0.y == p2.y;
+    let cross = (p1.x - p0.x) * (p2.y - p0.y) - (p1.y - p0.y) * (p2.x - p0.x);
+    let (center, ccw) = if closed {
+        // Full circle with the opposite point in the middle
+        (((p0.x + p1.x) / 2.0, (p0.y + p1.y) / 2.0), true)
+    } else if cross == 0.0 {
+        // Collinear points
+        return vec![*p1, *p2];
+    } else {
+        let (ax, ay) = (p1.x - p0.x, p1.y - p0.y);
+        let (bx, by) = (p2.x - p0.x, p2.y - p0.y);
+        let d = 2.0 * cross;
+        let a2 = ax * ax + ay * ay;
+        let b2 = bx * bx + by * by;
+        let cx = p0.x + (by * a2 - ay * b2) / d;
+        let cy = p0.y + (ax * b2 - bx * a2) / d;
+        ((cx, cy), cross > 0.0)
+    };
+    let radius = (p0.x - center.0).hypot(p0.y - center.1);
+    if radius == 0.0 {
+        return vec![*p2];
+    }
+    let angle = |p: &Coord| (p.y - center.1).atan2(p.x - center.0);
+    let sweep_to = |p: &Coord| {
+        let sweep = (angle(p) - angle(p0)).rem_euclid(TAU);
+        if ccw {
+            sweep
+        } else {
+            (TAU - sweep).rem_euclid(TAU)
+        }
+    };
+    let sweep = if closed { TAU } else { sweep_to(p2) };
+    let mid = if closed { PI } else { sweep_to(p1) };
+    let segments = (sweep / tolerance.max_step(radius)).ceil().max(1.0) as usize;
+    let start = angle(p0);
+    let direction = if ccw { 1.0 } else { -1.0 };
+    let mut points = Vec::with_capacity(segments);
+    for i in 1..segments {
+        let step = sweep * i as f64 / segments as f64;
+        let point = if step < mid {
+            arc_point(center, radius, start + direction * step, p0, p1, step / mid)
+        } else {
+            let f = (step - mid) / (sweep - mid);
+            arc_point(center, radius, start + direction * step, p1, p2, f)
+        };
+        points.push(point);
+    }
+    points.push(*p2);
+    points
+}
+
+/// Processor forwarding all events to a borrowed processor
+///
+/// Lets readers wrap the `&mut P` they were given into a [`LinearizeProcessor`].
+pub(crate) struct ProcessorRef<'a, P>(pub &'a mut P);
+
+impl<P: GeomProcessor> GeomProcessor for ProcessorRef<'_, P> {
+    forward!(
+        0 => dimensions, multi_dim, supports_curves, srid, xy, coordinate, empty_point, point_begin,
+        point_end, multipoint_begin, multipoint_end, linestring_begin, linestring_end,
+        multilinestring_begin, multilinestring_end, polygon_begin, polygon_end, multipolygon_begin,
+        multipolygon_end, geometrycollection_begin, geometrycollection_end, circularstring_begin,
+        circularstring_end, compoundcurve_begin, compoundcurve_end, curvepolygon_begin,
+        curvepolygon_end, multicurve_begin, multicurve_end, multisurface_begin, multisurface_end,
+        triangle_begin, triangle_end, polyhedralsurface_begin, polyhedralsurface_end, tin_begin,
+        tin_end,
+    );
+}
+
+/// Processor approximating curve geometries by linear geometries
+///
+/// Circular strings and compound curves are passed on as linestrings, curve polygons as
+/// polygons, multi curves as multilinestrings and multi surfaces as multipolygons.
+/// Linear geometries are passed unchanged.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(all(feature = "with-wkb", feature = "with-wkt"))]
+/// # {
+/// use geozero::wkb::Wkb;
+/// use geozero::wkt::WktWriter;
+/// use geozero::{ArcTolerance, GeozeroGeometry, LinearizeProcessor};
+///
+/// // CIRCULARSTRING(0 0,1 1,2 0)
+/// let wkb = Wkb(hex::decode("01080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F00000000000000400000000000000000").unwrap());
+/// let mut out: Vec<u8> = Vec::new();
+/// let tolerance = ArcTolerance::SegmentsPerQuadrant(1);
+/// let mut processor = LinearizeProcessor::new(WktWriter::new(&mut out), tolerance);
+/// wkb.process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "LINESTRING(0 0,1 1,2 0)");
+/// # }
+/// ```
+pub struct LinearizeProcessor<P> {
+    inner: P,
+    tolerance: ArcTolerance,
+    /// Open containers, `true` if members are tagged geometries
+    containers: Vec<bool>,
+    in_compound: bool,
+    /// Points of the current curve
+    line: Option<Vec<Coord>>,
+    /// Control points of the current circular string
+    arc: Option<Vec<Coord>>,
+}
+
+impl<P: GeomProcessor> LinearizeProcessor<P> {
+    pub fn new(inner: P, tolerance: ArcTolerance) -> Self {
+        LinearizeProcessor {
+            inner,
+            tolerance,
+            containers: Vec::new(),
+            in_compound: false,
+            line: None,
+            arc: None,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn member_tagged(&self) -> bool {
+        self.containers.last().copied().unwrap_or(true)
+    }
+
+    fn coord(&mut self, coord: Coord, idx: usize) -> Result<()> {
+        if let Some(arc) = &mut self.arc {
+            arc.push(coord);
+            Ok(())
+        } else if self.line.is_some() {
+            self.push(&[coord]);
+            Ok(())
+        } else {
+            self.emit(&coord, idx)
+        }
+    }
+
+    /// Append points to the current curve, skipping the joint point of segments
+    fn push(&mut self, coords: &[Coord]) {
+        let line = self.line.get_or_insert_with(Vec::new);
+        for coord in coords {
+            if line
+                .last()
+                .is_some_and(|last| last.x == coord.x && last.y == coord.y)
+            {
+                continue;
+            }
+            line.push(*coord);
+        }
+    }
+
+    fn emit(&mut self, c: &Coord, idx: usize) -> Result<()> {
+        if self.inner.multi_dim() {
+            self.inner.coordinate(c.x, c.y, c.z, c.m, c.t, c.tm, idx)
+        } else {
+            self.inner.xy(c.x, c.y, idx)
+        }
+    }
+
+    fn emit_line(&mut self, idx: usize) -> Result<()> {
+        let coords = self.line.take().unwrap_or_default();
+        let tagged = self.member_tagged();
+        self.inner.linestring_begin(tagged, coords.len(), idx)?;
+        for (i, coord) in coords.iter().enumerate() {
+            self.emit(coord, i)?;
+        }
+        self.inner.linestring_end(tagged, idx)
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for LinearizeProcessor<P> {
+    forward!(
+        inner => dataset_begin, schema, dataset_end, feature_begin, feature_end, properties_begin,
+        properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for LinearizeProcessor<P> {
+    forward!(inner => property);
+}
+
+impl<P: GeomProcessor> GeomProcessor for LinearizeProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let coord = Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+            t: None,
+            tm: None,
+        };
+        self.coord(coord, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let coord = Coord { x, y, z, m, t, tm };
+        self.coord(coord, idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if self.in_compound {
+            Ok(())
+        } else {
+            self.inner.linestring_begin(tagged, size, idx)
+        }
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if self.in_compound {
+            Ok(())
+        } else {
+            self.inner.linestring_end(tagged, idx)
+        }
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.containers.push(true);
+        self.inner.geometrycollection_begin(size, idx)
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.containers.pop();
+        self.inner.geometrycollection_end(idx)
+    }
+    fn circularstring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.arc = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        let arc = self.arc.take().unwrap_or_default();
+        self.push(&arc[..arc.len().min(1)]);
+        for i in (2..arc.len()).step_by(2) {
+            let points = linearize_arc(&arc[i - 2], &arc[i - 1], &arc[i], &self.tolerance);
+            self.push(&points);
+        }
+        if self.in_compound {
+            Ok(())
+        } else {
+            self.emit_line(idx)
+        }
+    }
+    fn compoundcurve_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.in_compound = true;
+        self.line = Some(Vec::with_capacity(size * 2));
+        Ok(())
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.in_compound = false;
+        self.emit_line(idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        let tagged = self.member_tagged();
+        self.containers.push(false);
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.containers.pop();
+        let tagged = self.member_tagged();
+        self.inner.polygon_end(tagged, idx)
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.containers.push(false);
+        self.inner.multilinestring_begin(size, idx)
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.containers.pop();
+        self.inner.multilinestring_end(idx)
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.containers.push(false);
+        self.inner.multipolygon_begin(size, idx)
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.containers.pop();
+        self.inner.multipolygon_end(idx)
+    }
+    forward!(
+        inner => dimensions, multi_dim, srid, empty_point, point_begin, point_end, multipoint_begin,
+        multipoint_end, multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, triangle_begin, triangle_end, polyhedralsurface_begin,
+        polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(all(feature = "with-wkb", feature = "with-wkt"))]
+    fn linearize(ewkb: &str, tolerance: ArcTolerance) -> String {
+        use crate::wkb::Ewkb;
+        use crate::wkt::WktWriter;
+        use crate::{CoordPrecision, GeozeroGeometry};
+
+        let mut out: Vec<u8> = Vec::new();
+        let writer = WktWriter::new(&mut out).with_precision(CoordPrecision::trimmed(3));
+        let mut processor = LinearizeProcessor::new(writer, tolerance);
+        Ewkb(hex::decode(ewkb).unwrap())
+            .process_geom(&mut processor)
+            .unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    #[cfg(all(feature = "with-wkb", feature = "with-wkt"))]
+    fn curves() {
+        let quadrant = ArcTolerance::SegmentsPerQuadrant(1);
+        // CIRCULARSTRING(0 0,1 1,2 0)
+        let arc = "01080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F00000000000000400000000000000000";
+        assert_eq!(linearize(arc, quadrant), "LINESTRING(0 0,1 1,2 0)");
+        assert_eq!(
+            linearize(arc, ArcTolerance::SegmentsPerQuadrant(2)),
+            "LINESTRING(0 0,0.293 0.707,1 1,1.707 0.707,2 0)"
+        );
+        // COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,3 0))
+        assert_eq!(
+            linearize("01090000000200000001080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F000000000000004000000000000000000102000000020000000000000000000040000000000000000000000000000008400000000000000000", quadrant),
+            "LINESTRING(0 0,1 1,2 0,3 0)"
+        );
+        // CURVEPOLYGON(COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,3 0,3 -1,0 -1,0 0)))
+        assert_eq!(
+            linearize("010A0000000100000001090000000200000001080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F0000000000000040000000000000000001020000000500000000000000000000400000000000000000000000000000084000000000000000000000000000000840000000000000F0BF0000000000000000000000000000F0BF00000000000000000000000000000000", quadrant),
+            "POLYGON((0 0,1 1,2 0,3 0,3 -1,0 -1,0 0))"
+        );
+        // MULTICURVE((0 0, 5 5),CIRCULARSTRING(4 0, 4 4, 8 4))
+        assert_eq!(
+            linearize("010B000000020000000102000000020000000000000000000000000000000000000000000000000014400000000000001440010800000003000000000000000000104000000000000000000000000000001040000000000000104000000000000020400000000000001040", quadrant),
+            "MULTILINESTRING((0 0,5 5),(4 0,4 4,8 4))"
+        );
+        // MULTISURFACE(CURVEPOLYGON(COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,3 0,3 -1,0 -1,0 0))))
+        assert_eq!(
+            linearize("010C00000001000000010A0000000100000001090000000200000001080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F0000000000000040000000000000000001020000000500000000000000000000400000000000000000000000000000084000000000000000000000000000000840000000000000F0BF0000000000000000000000000000F0BF00000000000000000000000000000000", quadrant),
+            "MULTIPOLYGON(((0 0,1 1,2 0,3 0,3 -1,0 -1,0 0)))"
+        );
+    }
+
+    #[test]
+    fn max_deviation() {
+        let (p0, p1, p2) = (
+            Coord {
+                x: 0.0,
+                y: 0.0,
+                z: None,
+                m: None,
+                t: None,
+                tm: None,
+            },
+            Coord {
+                x: 1.0,
+                y: 1.0,
+                z: None,
+                m: None,
+                t: None,
+                tm: None,
+            },
+            Coord {
+                x: 2.0,
+                y: 0.0,
+                z: None,
+                m: None,
+                t: None,
+                tm: None,
+            },
+        );
+        let points = linearize_arc(&p0, &p1, &p2, &ArcTolerance::MaxDeviation(0.01));
+        assert_eq!(points.len(), 12);
+        for pair in points.windows(2) {
+            let (mx, my) = ((pair[0].x + pair[1].x) / 2.0, (pair[0].y + pair[1].y) / 2.0);
+            assert!(1.0 - (mx - 1.0).hypot(my) <= 0.01);
+        }
+        // Collinear points
+        let p1 = Coord { y: 0.0, ..p1 };
+        assert_eq!(
+            linearize_arc(&p0, &p1, &p2, &ArcTolerance::default()).len(),
+            2
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn full_circle_and_z() -> Result<()> {
+        use crate::wkt::WktWriter;
+        use crate::{CoordDimensions, CoordPrecision};
+
+        let mut out: Vec<u8> = Vec::new();
+        let writer = WktWriter::new(&mut out).with_precision(CoordPrecision::trimmed(3));
+        let mut processor = LinearizeProcessor::new(writer, ArcTolerance::SegmentsPerQuadrant(1));
+        processor.circularstring_begin(3, 0)?;
+        processor.xy(0.0, 0.0, 0)?;
+        processor.xy(2.0, 0.0, 1)?;
+        processor.xy(0.0, 0.0, 2)?;
+        processor.circularstring_end(0)?;
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "LINESTRING(0 0,1 -1,2 0,1 1,0 0)"
+        );
+
+        let mut out: Vec<u8> = Vec::new();
+        let writer = WktWriter::with_dims(&mut out, CoordDimensions::xyz())
+            .with_precision(CoordPrecision::trimmed(3));
+        let mut processor = LinearizeProcessor::new(writer, ArcTolerance::SegmentsPerQuadrant(2));
+        processor.circularstring_begin(3, 0)?;
+        processor.coordinate(0.0, 0.0, Some(0.0), None, None, None, 0)?;
+        processor.coordinate(1.0, 1.0, Some(10.0), None, None, None, 1)?;
+        processor.coordinate(2.0, 0.0, Some(20.0), None, None, None, 2)?;
+        processor.circularstring_end(0)?;
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "LINESTRING(0 0 0,0.293 0.707 5,1 1 10,1.707 0.707 15,2 0 20)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "with-wkb", feature = "with-geojson"))]
+    fn curve_unaware_writer() {
+        use crate::wkb::Ewkb;
+        use crate::ToJson;
+
+        // MULTICURVE((0 0, 5 5),CIRCULARSTRING(4 0, 4 4, 8 4))
+        let ewkb = Ewkb(hex::decode("010B000000020000000102000000020000000000000000000000000000000000000000000000000014400000000000001440010800000003000000000000000000104000000000000000000000000000001040000000000000104000000000000020400000000000001040").unwrap());
+        let json: serde_json::Value = serde_json::from_str(&ewkb.to_json().unwrap()).unwrap();
+        assert_eq!(json["type"], "MultiLineString");
+        let lines = json["coordinates"].as_array().unwrap();
+        assert_eq!(lines[0].as_array().unwrap().len(), 2);
+        // Half circle with 32 segments per quadrant
+        assert_eq!(lines[1].as_array().unwrap().len(), 65);
+    }
+}
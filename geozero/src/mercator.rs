@@ -0,0 +1,184 @@
+//! Web Mercator (EPSG:3857) projection and XYZ tile math.
+use crate::error::{GeozeroError, Result};
+use crate::{Bbox, CoordTransform};
+use std::f64::consts::PI;
+
+/// Equatorial radius of the WGS84 ellipsoid used by Web Mercator
+pub const EARTH_RADIUS: f64 = 6378137.0;
+
+/// Half the width of the Web Mercator world in meters
+pub const MAX_EXTENT: f64 = PI * EARTH_RADIUS;
+
+/// Latitude where Web Mercator becomes a square
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Highest supported zoom level, the last one with tile indices fitting into `u32`
+pub const MAX_ZOOM: u8 = 32;
+
+/// Project WGS84 longitude/latitude to Web Mercator, clamping latitudes to [`MAX_LATITUDE`]
+pub fn lonlat_to_mercator(lon: f64, lat: f64) -> (f64, f64) {
+    let lat = lat.clamp(-MAX_LATITUDE, MAX_LATITUDE);
+    let x = lon.to_radians() * EARTH_RADIUS;
+    let y = (PI / 4.0 + lat.to_radians() / 2.0).tan().ln() * EARTH_RADIUS;
+    (x, y)
+}
+
+/// Unproject Web Mercator coordinates to WGS84 longitude/latitude
+pub fn mercator_to_lonlat(x: f64, y: f64) -> (f64, f64) {
+    let lon = (x / EARTH_RADIUS).to_degrees();
+    let lat = (2.0 * (y / EARTH_RADIUS).exp().atan() - PI / 2.0).to_degrees();
+    (lon, lat)
+}
+
+/// Transformation from EPSG:4326 to EPSG:3857, to be used with [`TransformProcessor`](crate::TransformProcessor)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Wgs84ToWebMercator;
+
+impl CoordTransform for Wgs84ToWebMercator {
+    fn transform(&mut self, x: f64, y: f64, z: Option<f64>) -> Result<(f64, f64, Option<f64>)> {
+        let (x, y) = lonlat_to_mercator(x, y);
+        Ok((x, y, z))
+    }
+    fn transform_srid(&mut self, _srid: Option<i32>) -> Result<Option<i32>> {
+        Ok(Some(3857))
+    }
+}
+
+/// Transformation from EPSG:3857 to EPSG:4326, to be used with [`TransformProcessor`](crate::TransformProcessor)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WebMercatorToWgs84;
+
+impl CoordTransform for WebMercatorToWgs84 {
+    fn transform(&mut self, x: f64, y: f64, z: Option<f64>) -> Result<(f64, f64, Option<f64>)> {
+        let (x, y) = mercator_to_lonlat(x, y);
+        Ok((x, y, z))
+    }
+    fn transform_srid(&mut self, _srid: Option<i32>) -> Result<Option<i32>> {
+        Ok(Some(4326))
+    }
+}
+
+/// Number of tiles along each axis at zoom level `z`
+fn tile_count(z: u8) -> Result<u64> {
+    if z > MAX_ZOOM {
+        return Err(GeozeroError::Tile(format!(
+            "zoom level {z} exceeds maximum {MAX_ZOOM}"
+        )));
+    }
+    Ok(1u64 << z)
+}
+
+/// Web Mercator bounds of XYZ tile `z`/`x`/`y` (origin top left)
+///
+/// Fails for zoom levels above [`MAX_ZOOM`] and tile indices outside of the zoom level.
+pub fn tile_bounds(z: u8, x: u32, y: u32) -> Result<Bbox> {
+    let count = tile_count(z)?;
+    if u64::from(x) >= count || u64::from(y) >= count {
+        return Err(GeozeroError::Tile(format!(
+            "{z}/{x}/{y} outside of zoom level {z}"
+        )));
+    }
+    let size = 2.0 * MAX_EXTENT / count as f64;
+    let minx = -MAX_EXTENT + x as f64 * size;
+    let maxy = MAX_EXTENT - y as f64 * size;
+    Ok(Bbox::new(minx, maxy - size, minx + size, maxy))
+}
+
+/// XYZ tiles `(x, y)` at zoom level `z` intersecting the Web Mercator `bbox`
+///
+/// Tiles only touching the bbox at their left or top edge are not included.
+/// Fails for zoom levels above [`MAX_ZOOM`].
+pub fn tiles_covering(bbox: &Bbox, z: u8) -> Result<impl Iterator<Item = (u32, u32)>> {
+    let count = tile_count(z)?;
+    let size = 2.0 * MAX_EXTENT / count as f64;
+    let max_idx = (count - 1) as f64;
+    let tile_idx = |v: f64| v.clamp(0.0, max_idx) as u32;
+    let minx = tile_idx(((bbox.minx + MAX_EXTENT) / size).floor());
+    let maxx = tile_idx(((bbox.maxx + MAX_EXTENT) / size).ceil() - 1.0).max(minx);
+    let miny = tile_idx(((MAX_EXTENT - bbox.maxy) / size).floor());
+    let maxy = tile_idx(((MAX_EXTENT - bbox.miny) / size).ceil() - 1.0).max(miny);
+    Ok((miny..=maxy).flat_map(move |y| (minx..=maxx).map(move |x| (x, y))))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(a: (f64, f64), b: (f64, f64)) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn projection() {
+        assert_close(lonlat_to_mercator(0.0, 0.0), (0.0, 0.0));
+        assert_close(
+            lonlat_to_mercator(180.0, MAX_LATITUDE),
+            (MAX_EXTENT, MAX_EXTENT),
+        );
+        assert_close(lonlat_to_mercator(0.0, 90.0), (0.0, MAX_EXTENT));
+        assert_close(
+            lonlat_to_mercator(8.5, 47.4),
+            (946215.671743, 6007610.414388),
+        );
+        assert_close(
+            mercator_to_lonlat(946215.671743, 6007610.414388),
+            (8.5, 47.4),
+        );
+    }
+
+    #[test]
+    fn tiles() {
+        assert_eq!(
+            tile_bounds(0, 0, 0).unwrap(),
+            Bbox::new(-MAX_EXTENT, -MAX_EXTENT, MAX_EXTENT, MAX_EXTENT)
+        );
+        assert_eq!(
+            tile_bounds(1, 1, 0).unwrap(),
+            Bbox::new(0.0, 0.0, MAX_EXTENT, MAX_EXTENT)
+        );
+        let world = tile_bounds(0, 0, 0).unwrap();
+        assert_eq!(
+            tiles_covering(&world, 0).unwrap().collect::<Vec<_>>(),
+            [(0, 0)]
+        );
+        assert_eq!(
+            tiles_covering(&world, 1).unwrap().collect::<Vec<_>>(),
+            [(0, 0), (1, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(
+            tiles_covering(&tile_bounds(1, 1, 0).unwrap(), 1)
+                .unwrap()
+                .collect::<Vec<_>>(),
+            [(1, 0)]
+        );
+        let (x, y) = lonlat_to_mercator(8.5, 47.4);
+        let point = Bbox::from_point(x, y);
+        assert_eq!(
+            tiles_covering(&point, 10).unwrap().collect::<Vec<_>>(),
+            [(536, 358)]
+        );
+        assert!(tile_bounds(10, 536, 358).unwrap().contains(&point));
+    }
+
+    #[test]
+    fn max_zoom() {
+        let bounds = tile_bounds(MAX_ZOOM, u32::MAX, 0).unwrap();
+        assert_close((bounds.maxx, bounds.maxy), (MAX_EXTENT, MAX_EXTENT));
+        let point = Bbox::from_point(MAX_EXTENT, MAX_EXTENT);
+        assert_eq!(
+            tiles_covering(&point, MAX_ZOOM)
+                .unwrap()
+                .collect::<Vec<_>>(),
+            [(u32::MAX, 0)]
+        );
+
+        assert!(tile_bounds(MAX_ZOOM + 1, 0, 0).is_err());
+        assert!(tile_bounds(64, 0, 0).is_err());
+        assert!(tiles_covering(&point, 64).is_err());
+        assert!(tile_bounds(1, 2, 0).is_err());
+        assert!(tile_bounds(1, 0, 2).is_err());
+    }
+}
@@ -2,6 +2,7 @@ use crate::error::Result;
 use crate::feature_processor::FeatureProcessor;
 use crate::geometry_processor::GeomProcessor;
 use crate::property_processor::{ColumnValue, PropertyProcessor};
+use crate::schema::Schema;
 
 #[doc(hidden)]
 pub struct Multiplexer<P1: FeatureProcessor, P2: FeatureProcessor> {
@@ -20,6 +21,10 @@ impl<P1: FeatureProcessor, P2: FeatureProcessor> FeatureProcessor for Multiplexe
         self.p1.dataset_begin(name)?;
         self.p2.dataset_begin(name)
     }
+    fn schema(&mut self, schema: &Schema) -> Result<()> {
+        self.p1.schema(schema)?;
+        self.p2.schema(schema)
+    }
     fn dataset_end(&mut self) -> Result<()> {
         self.p1.dataset_end()?;
         self.p2.dataset_end()
@@ -51,6 +56,9 @@ impl<P1: FeatureProcessor, P2: FeatureProcessor> FeatureProcessor for Multiplexe
 }
 
 impl<P1: FeatureProcessor, P2: FeatureProcessor> GeomProcessor for Multiplexer<P1, P2> {
+    fn supports_curves(&self) -> bool {
+        self.p1.supports_curves() && self.p2.supports_curves()
+    }
     fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
         self.p1.xy(x, y, idx)?;
         self.p2.xy(x, y, idx)
@@ -19,9 +19,10 @@ pub use vector_tile::*;
 
 pub(crate) mod conversion {
     use crate::error::Result;
+    use crate::mercator::{tile_bounds, Wgs84ToWebMercator};
     use crate::mvt::vector_tile::tile;
     use crate::mvt::MvtWriter;
-    use crate::GeozeroGeometry;
+    use crate::{GeozeroDatasource, GeozeroGeometry, TransformProcessor};
 
     /// Convert to MVT geometry.
     pub trait ToMvt {
@@ -41,6 +42,26 @@ pub(crate) mod conversion {
 
         /// Convert to MVT geometry with geometries in unmodified tile coordinate space.
         fn to_mvt_unscaled(&self) -> Result<tile::Feature>;
+
+        /// Convert to MVT geometry scaled into a square tile fitted to the geometry bounds.
+        ///
+        /// # Arguments
+        /// * `extent` - Size of MVT tile in tile coordinate space (e.g. 4096).
+        fn to_mvt_fitted(&self, extent: u32) -> Result<tile::Feature>;
+
+        /// Convert to MVT geometry of XYZ tile `z`/`x`/`y`, with geometries in Web Mercator (EPSG:3857).
+        ///
+        /// # Arguments
+        /// * `z`, `x`, `y` - XYZ tile coordinates (origin top left).
+        /// * `extent` - Size of MVT tile in tile coordinate space (e.g. 4096).
+        fn to_mvt_tile(&self, z: u8, x: u32, y: u32, extent: u32) -> Result<tile::Feature>;
+
+        /// Convert to MVT geometry of XYZ tile `z`/`x`/`y`, projecting geometries from WGS84 (EPSG:4326).
+        ///
+        /// # Arguments
+        /// * `z`, `x`, `y` - XYZ tile coordinates (origin top left).
+        /// * `extent` - Size of MVT tile in tile coordinate space (e.g. 4096).
+        fn to_mvt_tile_wgs84(&self, z: u8, x: u32, y: u32, extent: u32) -> Result<tile::Feature>;
     }
 
     impl<T: GeozeroGeometry> ToMvt for T {
@@ -62,6 +83,69 @@ pub(crate) mod conversion {
             self.process_geom(&mut mvt)?;
             Ok(mvt.feature)
         }
+
+        fn to_mvt_fitted(&self, extent: u32) -> Result<tile::Feature> {
+            let Some(bbox) = self.bbox()? else {
+                return self.to_mvt_unscaled();
+            };
+            // Keep the aspect ratio and avoid a zero sized tile for points
+            let size = (bbox.maxx - bbox.minx).max(bbox.maxy - bbox.miny);
+            let size = if size > 0.0 { size } else { 1.0 };
+            self.to_mvt(
+                extent,
+                bbox.minx,
+                bbox.miny,
+                bbox.minx + size,
+                bbox.miny + size,
+            )
+        }
+
+        fn to_mvt_tile(&self, z: u8, x: u32, y: u32, extent: u32) -> Result<tile::Feature> {
+            let bounds = tile_bounds(z, x, y)?;
+            self.to_mvt(extent, bounds.minx, bounds.miny, bounds.maxx, bounds.maxy)
+        }
+
+        fn to_mvt_tile_wgs84(&self, z: u8, x: u32, y: u32, extent: u32) -> Result<tile::Feature> {
+            let bounds = tile_bounds(z, x, y)?;
+            let mvt = MvtWriter::new(extent, bounds.minx, bounds.miny, bounds.maxx, bounds.maxy);
+            let mut processor = TransformProcessor::new(mvt, Wgs84ToWebMercator);
+            self.process_geom(&mut processor)?;
+            Ok(processor.into_inner().feature)
+        }
+    }
+
+    /// Consume features into a MVT layer
+    pub trait ProcessToMvt {
+        /// Consume features into MVT layer `name`.
+        ///
+        /// # Arguments
+        /// * `extent` - Size of MVT tile in tile coordinate space (e.g. 4096).
+        /// * `left`, `bottom`, `right`, `top` - Bounds of tile in map coordinate space, with no buffer.
+        fn to_mvt_layer(
+            &mut self,
+            name: &str,
+            extent: u32,
+            left: f64,
+            bottom: f64,
+            right: f64,
+            top: f64,
+        ) -> Result<tile::Layer>;
+    }
+
+    impl<T: GeozeroDatasource> ProcessToMvt for T {
+        fn to_mvt_layer(
+            &mut self,
+            name: &str,
+            extent: u32,
+            left: f64,
+            bottom: f64,
+            right: f64,
+            top: f64,
+        ) -> Result<tile::Layer> {
+            let mut mvt = MvtWriter::new(extent, left, bottom, right, top);
+            self.process(&mut mvt)?;
+            Ok(mvt.into_layer(name))
+        }
     }
 }
 
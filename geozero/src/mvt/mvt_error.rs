@@ -11,6 +11,8 @@ pub enum MvtError {
     InvalidValueIndex(u32),
     #[error("unsupported value type for key {0}")]
     UnsupportedKeyValueType(String),
+    #[error("unsupported property value {0}")]
+    UnsupportedPropertyValue(String),
     #[error("geometry format")]
     GeometryFormat,
     #[error("too few coordinates in line or ring")]
@@ -1,6 +1,9 @@
 use crate::error::Result;
 use crate::mvt::vector_tile::{tile, tile::GeomType};
-use crate::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry};
+use crate::{
+    Column, ColumnValue, FeatureIter, FeatureProcessor, FeatureSource, GeomProcessor,
+    GeozeroDatasource, GeozeroGeometry, Schema,
+};
 
 use super::{
     mvt_commands::{Command, CommandInteger, ParameterInteger},
@@ -13,21 +16,58 @@ impl GeozeroDatasource for tile::Layer {
     }
 }
 
+impl tile::Layer {
+    /// Iterate over owned features
+    pub fn features(&self) -> FeatureIter<&tile::Layer> {
+        FeatureIter::new(self)
+    }
+}
+
+impl FeatureSource for &tile::Layer {
+    fn process_next_feature<P: FeatureProcessor>(
+        &mut self,
+        idx: u64,
+        processor: &mut P,
+    ) -> Result<bool> {
+        let Some(feature) = self.features.get(idx as usize) else {
+            return Ok(false);
+        };
+        process_feature(self, idx, feature, processor)?;
+        Ok(true)
+    }
+}
+
 /// Process MVT layer.
 pub fn process(layer: &tile::Layer, processor: &mut impl FeatureProcessor) -> Result<()> {
     processor.dataset_begin(Some(&layer.name))?;
+    // Value types are declared per feature, not per key
+    let columns = layer
+        .keys
+        .iter()
+        .map(|key| Column::new(key, None))
+        .collect();
+    processor.schema(&Schema::new(columns))?;
     for (idx, feature) in layer.features.iter().enumerate() {
-        processor.feature_begin(idx as u64)?;
+        process_feature(layer, idx as u64, feature, processor)?;
+    }
+    processor.dataset_end()
+}
 
-        process_properties(layer, feature, processor)?;
+fn process_feature(
+    layer: &tile::Layer,
+    idx: u64,
+    feature: &tile::Feature,
+    processor: &mut impl FeatureProcessor,
+) -> Result<()> {
+    processor.feature_begin(idx)?;
 
-        processor.geometry_begin()?;
-        process_geom(feature, processor)?;
-        processor.geometry_end()?;
+    process_properties(layer, feature, processor)?;
 
-        processor.feature_end(idx as u64)?;
-    }
-    processor.dataset_end()
+    processor.geometry_begin()?;
+    process_geom(feature, processor)?;
+    processor.geometry_end()?;
+
+    processor.feature_end(idx)
 }
 
 fn process_properties(
@@ -388,6 +428,18 @@ mod test {
                 ]
             })
         );
+
+        let features = mvt_layer.features().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[1].idx, 1);
+        assert_eq!(
+            features[1].property_value("count"),
+            Some(&crate::OwnedColumnValue::Long(2))
+        );
+        assert_eq!(
+            features[1].to_json().unwrap(),
+            r#"{"type": "Point", "coordinates": [1205,1540]}"#
+        );
     }
 
     #[test]
@@ -4,14 +4,26 @@
 use crate::error::Result;
 use crate::mvt::mvt_commands::{Command, CommandInteger, ParameterInteger};
 use crate::mvt::vector_tile::{tile, tile::GeomType};
-use crate::GeomProcessor;
+use crate::mvt::TagsBuilder;
+use crate::orient::orient_ring;
+use crate::{ColumnValue, FeatureProcessor, GeomProcessor, PropertyProcessor, Winding};
 
 use super::mvt_error::MvtError;
 
 /// Generator for MVT geometry type.
+///
+/// Polygon rings are rewound to the MVT winding order, with exterior rings appearing clockwise
+/// in tile coordinates.
+///
+/// Processing a dataset collects its features with their tags into a layer,
+/// see [`MvtWriter::into_layer`]. NULL property values are omitted from the tags.
 #[derive(Default, Debug)]
 pub struct MvtWriter {
     pub(crate) feature: tile::Feature,
+    /// Completed features of the layer
+    features: Vec<tile::Feature>,
+    /// Keys and values of the layer
+    tags: TagsBuilder<String>,
     // Extent, 0 for unscaled
     extent: i32,
     // Scale geometry to bounds
@@ -24,6 +36,8 @@ pub struct MvtWriter {
     last_y: i32,
     line_state: LineState,
     is_multiline: bool,
+    /// Coordinates of the current polygon ring
+    ring: Option<Vec<(f64, f64)>>,
 }
 
 #[derive(Default, Debug, PartialEq)]
@@ -52,18 +66,30 @@ impl MvtWriter {
         &self.feature
     }
 
-    fn reserve(&mut self, capacity: usize) {
-        let total = self.feature.geometry.len() + capacity;
-        if total > self.feature.geometry.capacity() {
-            self.feature
-                .geometry
-                .reserve(total - self.feature.geometry.capacity());
+    /// Layer `name` with all processed features
+    pub fn into_layer(self, name: &str) -> tile::Layer {
+        let (keys, values) = self.tags.into_tags();
+        tile::Layer {
+            version: 2,
+            name: name.to_string(),
+            features: self.features,
+            keys,
+            values: values.into_iter().map(Into::into).collect(),
+            extent: (self.extent != 0).then_some(self.extent as u32),
         }
     }
-}
 
-impl GeomProcessor for MvtWriter {
-    fn xy(&mut self, x_coord: f64, y_coord: f64, idx: usize) -> Result<()> {
+    /// Exterior ring winding order of input coordinates
+    fn exterior_winding(&self) -> Winding {
+        if self.extent != 0 {
+            // Y axis is reversed when scaling to tile coordinates
+            Winding::Clockwise
+        } else {
+            Winding::CounterClockwise
+        }
+    }
+
+    fn write_xy(&mut self, x_coord: f64, y_coord: f64, idx: usize) -> Result<()> {
         // Omit last coord of ring (emit ClosePath instead)
         let last_ring_coord = if let LineState::Ring(size) = self.line_state {
             idx == size - 1
@@ -106,6 +132,49 @@ impl GeomProcessor for MvtWriter {
         Ok(())
     }
 
+    fn reserve(&mut self, capacity: usize) {
+        let total = self.feature.geometry.len() + capacity;
+        if total > self.feature.geometry.capacity() {
+            self.feature
+                .geometry
+                .reserve(total - self.feature.geometry.capacity());
+        }
+    }
+}
+
+impl FeatureProcessor for MvtWriter {
+    fn feature_end(&mut self, _idx: u64) -> Result<()> {
+        self.features.push(std::mem::take(&mut self.feature));
+        // Geometry commands of each feature start at the tile origin
+        self.last_x = 0;
+        self.last_y = 0;
+        Ok(())
+    }
+}
+
+impl PropertyProcessor for MvtWriter {
+    fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        if let Some((key_idx, value_idx)) = self.tags.insert_property(name.to_string(), value)? {
+            self.feature.tags.extend([key_idx, value_idx]);
+        }
+        Ok(false)
+    }
+}
+
+impl GeomProcessor for MvtWriter {
+    fn supports_curves(&self) -> bool {
+        false
+    }
+    fn xy(&mut self, x_coord: f64, y_coord: f64, idx: usize) -> Result<()> {
+        match &mut self.ring {
+            Some(ring) => {
+                ring.push((x_coord, y_coord));
+                Ok(())
+            }
+            None => self.write_xy(x_coord, y_coord, idx),
+        }
+    }
+
     fn point_begin(&mut self, _idx: usize) -> Result<()> {
         self.feature.set_type(GeomType::Point);
         self.reserve(3);
@@ -133,6 +202,7 @@ impl GeomProcessor for MvtWriter {
             LineState::Line(size)
         } else {
             self.reserve(2 + 2 * (size - 1) + 1);
+            self.ring = Some(Vec::with_capacity(size));
             LineState::Ring(size)
         };
         self.feature
@@ -141,7 +211,13 @@ impl GeomProcessor for MvtWriter {
         Ok(())
     }
 
-    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+    fn linestring_end(&mut self, _tagged: bool, idx: usize) -> Result<()> {
+        if let Some(mut ring) = self.ring.take() {
+            orient_ring(&mut ring, idx, self.exterior_winding(), |c| *c);
+            for (i, (x, y)) in ring.into_iter().enumerate() {
+                self.write_xy(x, y, i)?;
+            }
+        }
         if let LineState::Ring(_) = self.line_state {
             self.feature
                 .geometry
@@ -439,6 +515,15 @@ mod test {
         assert_eq!(mvt.geometry, [9, 6, 12, 18, 10, 12, 24, 44, 15]);
     }
 
+    #[test]
+    fn polygon_winding() {
+        // Clockwise exterior is rewound
+        let geojson =
+            GeoJson(r#"{"type": "Polygon", "coordinates": [[[3, 6], [20, 34], [8, 12], [3, 6]]]}"#);
+        let mvt = geojson.to_mvt_unscaled().unwrap();
+        assert_eq!(mvt.geometry, [9, 6, 12, 18, 10, 12, 24, 44, 15]);
+    }
+
     #[test]
     fn multipolygon_geom() {
         let geojson = r#"{
@@ -487,6 +572,47 @@ mod test {
         );
     }
 
+    #[test]
+    fn fitted_geom() {
+        let geojson = GeoJson(r#"{"type": "LineString", "coordinates": [[10,10], [20,30]]}"#);
+        let mvt = geojson.to_mvt_fitted(4096).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&mvt.to_json().unwrap()).unwrap(),
+            json!({
+                "type": "LineString",
+                "coordinates": [[0,4096],[2048,0]]
+            })
+        );
+        let geojson = GeoJson(r#"{"type": "Point", "coordinates": [25, 17]}"#);
+        let mvt = geojson.to_mvt_fitted(4096).unwrap();
+        assert_eq!(mvt.geometry, [9, 0, 8192]);
+    }
+
+    #[test]
+    fn tile_geom() {
+        use crate::mercator::{mercator_to_lonlat, MAX_EXTENT};
+        use crate::{GeomProcessor, RecordedGeometry};
+
+        // Center of tile 1/1/0, shifted by 1m to avoid rounding at pixel edges
+        let (x, y) = (MAX_EXTENT / 2.0 + 1.0, MAX_EXTENT / 2.0 + 1.0);
+        let geojson = GeoJson(&format!(
+            r#"{{"type": "Point", "coordinates": [{x}, {y}]}}"#
+        ));
+        let mvt = geojson.to_mvt_tile(1, 1, 0, 4096).unwrap();
+        assert_eq!(mvt.geometry, [9, 4096, 4096]);
+
+        // Projected from WGS84
+        let mut geom = RecordedGeometry::default();
+        geom.point_begin(0).unwrap();
+        let (lon, lat) = mercator_to_lonlat(x, y);
+        geom.xy(lon, lat, 0).unwrap();
+        geom.point_end(0).unwrap();
+        let mvt = geom.to_mvt_tile_wgs84(1, 1, 0, 4096).unwrap();
+        assert_eq!(mvt.geometry, [9, 4096, 4096]);
+
+        assert!(geojson.to_mvt_tile(1, 2, 0, 4096).is_err());
+    }
+
     #[test]
     #[cfg(feature = "with-geo")]
     fn geo_screen_coords_to_mvt() -> Result<()> {
@@ -512,4 +638,42 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn features_to_layer() {
+        use crate::mvt::TileValue;
+        use crate::ProcessToMvt;
+
+        let mut geojson = GeoJson(
+            r#"{"type": "FeatureCollection", "features": [
+                {"type": "Feature", "properties": {"name": "a", "pop": null}, "geometry": {"type": "Point", "coordinates": [1, 1]}},
+                {"type": "Feature", "properties": {"name": "b", "pop": 3}, "geometry": {"type": "Point", "coordinates": [2, 2]}}
+            ]}"#,
+        );
+        let layer = geojson
+            .to_mvt_layer("places", 4096, 0.0, 0.0, 4096.0, 4096.0)
+            .unwrap();
+        assert_eq!(layer.name, "places");
+        assert_eq!(layer.extent, Some(4096));
+        assert_eq!(layer.keys, ["name", "pop"]);
+        let values: Vec<TileValue> = layer
+            .values
+            .iter()
+            .map(|v| v.clone().try_into().unwrap())
+            .collect();
+        assert_eq!(
+            values,
+            [
+                TileValue::Str("a".to_string()),
+                TileValue::Str("b".to_string()),
+                TileValue::Int(3)
+            ]
+        );
+        // NULL value is omitted
+        assert_eq!(layer.features[0].tags, [0, 0]);
+        assert_eq!(layer.features[1].tags, [0, 1, 1, 2]);
+        // Each feature geometry starts at the tile origin
+        assert_eq!(layer.features[0].geometry, [9, 2, 8190]);
+        assert_eq!(layer.features[1].geometry, [9, 4, 8188]);
+    }
 }
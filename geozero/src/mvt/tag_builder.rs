@@ -1,4 +1,7 @@
+use crate::error::Result;
 use crate::mvt::tile_value::TileValue;
+use crate::mvt::MvtError;
+use crate::ColumnValue;
 use dup_indexer::{DupIndexer, PtrRead};
 use std::hash::Hash;
 
@@ -47,6 +50,33 @@ impl<K: Eq + Hash + PtrRead> TagsBuilder<K> {
         )
     }
 
+    /// Insert a feature property value.
+    ///
+    /// NULL values are encoded by omitting the tag, in which case `None` is returned.
+    pub fn insert_property(&mut self, key: K, value: &ColumnValue) -> Result<Option<(u32, u32)>> {
+        let value = match *value {
+            ColumnValue::Null => return Ok(None),
+            ColumnValue::Byte(v) => TileValue::Int(v.into()),
+            ColumnValue::UByte(v) => TileValue::Uint(v.into()),
+            ColumnValue::Bool(v) => TileValue::Bool(v),
+            ColumnValue::Short(v) => TileValue::Int(v.into()),
+            ColumnValue::UShort(v) => TileValue::Uint(v.into()),
+            ColumnValue::Int(v) => TileValue::Int(v.into()),
+            ColumnValue::UInt(v) => TileValue::Uint(v.into()),
+            ColumnValue::Long(v) => TileValue::Int(v),
+            ColumnValue::ULong(v) => TileValue::Uint(v),
+            ColumnValue::Float(v) => TileValue::Float(v),
+            ColumnValue::Double(v) => TileValue::Double(v),
+            ColumnValue::String(v) | ColumnValue::Json(v) | ColumnValue::DateTime(v) => {
+                TileValue::Str(v.to_string())
+            }
+            ColumnValue::Binary(_) => {
+                return Err(MvtError::UnsupportedPropertyValue(format!("{value:?}")).into())
+            }
+        };
+        Ok(Some(self.insert(key, value)))
+    }
+
     pub fn into_tags(self) -> (Vec<K>, Vec<TileValue>) {
         (self.keys.into_vec(), self.values.into_vec())
     }
@@ -73,4 +103,26 @@ mod tests {
         assert_eq!(vec![s("foo"), s("bar")], keys);
         assert_eq!(vec![Str(s("bar")), Str(s("baz")), Int(42)], values);
     }
+
+    #[test]
+    fn test_insert_property() {
+        let mut lb = TagsBuilder::new();
+        assert_eq!(
+            Some((0, 0)),
+            lb.insert_property(s("foo"), &ColumnValue::String("bar"))
+                .unwrap()
+        );
+        assert_eq!(
+            None,
+            lb.insert_property(s("baz"), &ColumnValue::Null).unwrap()
+        );
+        assert_eq!(
+            Some((1, 1)),
+            lb.insert_property(s("pop"), &ColumnValue::Int(42)).unwrap()
+        );
+
+        let (keys, values) = lb.into_tags();
+        assert_eq!(vec![s("foo"), s("pop")], keys);
+        assert_eq!(vec![Str(s("bar")), Int(42)], values);
+    }
 }
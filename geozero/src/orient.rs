@@ -0,0 +1,253 @@
+use crate::coord::Coord;
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::PropertyProcessor;
+
+/// Winding order of a polygon ring, with the Y axis pointing up
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl Winding {
+    /// Winding order of a ring, `None` if the ring has no area
+    pub fn of_ring<I: IntoIterator<Item = (f64, f64)>>(ring: I) -> Option<Winding> {
+        let mut points = ring.into_iter();
+        let (x0, y0) = points.next()?;
+        // Shoelace formula relative to the first point, for numeric stability
+        let (mut area, mut prev) = (0.0, (0.0, 0.0));
+        for (x, y) in points {
+            let (dx, dy) = (x - x0, y - y0);
+            area += prev.0 * dy - dx * prev.1;
+            prev = (dx, dy);
+        }
+        if area > 0.0 {
+            Some(Winding::CounterClockwise)
+        } else if area < 0.0 {
+            Some(Winding::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// Opposite winding order
+    pub fn reverse(self) -> Winding {
+        match self {
+            Winding::Clockwise => Winding::CounterClockwise,
+            Winding::CounterClockwise => Winding::Clockwise,
+        }
+    }
+}
+
+/// Reverse ring `idx` of a polygon if it doesn't follow the exterior winding order
+pub(crate) fn orient_ring<T>(
+    ring: &mut [T],
+    idx: usize,
+    exterior: Winding,
+    xy: fn(&T) -> (f64, f64),
+) {
+    let expected = if idx == 0 {
+        exterior
+    } else {
+        exterior.reverse()
+    };
+    if Winding::of_ring(ring.iter().map(xy)).is_some_and(|winding| winding != expected) {
+        ring.reverse();
+    }
+}
+
+/// Processor rewinding polygon rings to a winding order convention
+///
+/// Exterior rings are passed on with the given winding order, interior rings with the opposite
+/// one. Rings without area and rings of curve polygons are passed unchanged.
+/// RFC 7946 GeoJSON expects counter-clockwise exterior rings, shapefiles clockwise ones.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::{GeozeroGeometry, OrientRings, Winding};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = OrientRings::new(WktWriter::new(&mut out), Winding::CounterClockwise);
+/// Wkt("POLYGON((0 0,0 1,1 1,1 0,0 0))").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "POLYGON((0 0,1 0,1 1,0 1,0 0))");
+/// # }
+/// ```
+pub struct OrientRings<P> {
+    inner: P,
+    exterior: Winding,
+    in_polygon: bool,
+    /// Coordinates of the current ring
+    ring: Option<Vec<Coord>>,
+}
+
+impl<P: GeomProcessor> OrientRings<P> {
+    pub fn new(inner: P, exterior: Winding) -> Self {
+        OrientRings {
+            inner,
+            exterior,
+            in_polygon: false,
+            ring: None,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn coord(&mut self, coord: Coord, idx: usize) -> Result<()> {
+        match &mut self.ring {
+            Some(ring) => {
+                ring.push(coord);
+                Ok(())
+            }
+            None => self.emit(&coord, idx),
+        }
+    }
+
+    fn emit(&mut self, c: &Coord, idx: usize) -> Result<()> {
+        if self.inner.multi_dim() {
+            self.inner.coordinate(c.x, c.y, c.z, c.m, c.t, c.tm, idx)
+        } else {
+            self.inner.xy(c.x, c.y, idx)
+        }
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for OrientRings<P> {
+    forward!(
+        inner => dataset_begin, schema, dataset_end, feature_begin, feature_end, properties_begin,
+        properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for OrientRings<P> {
+    forward!(inner => property);
+}
+
+impl<P: GeomProcessor> GeomProcessor for OrientRings<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let coord = Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+            t: None,
+            tm: None,
+        };
+        self.coord(coord, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let coord = Coord { x, y, z, m, t, tm };
+        self.coord(coord, idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if self.in_polygon && !tagged {
+            self.ring = Some(Vec::with_capacity(size));
+            Ok(())
+        } else {
+            self.inner.linestring_begin(tagged, size, idx)
+        }
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        let Some(mut ring) = self.ring.take() else {
+            return self.inner.linestring_end(tagged, idx);
+        };
+        orient_ring(&mut ring, idx, self.exterior, |c| (c.x, c.y));
+        self.inner.linestring_begin(tagged, ring.len(), idx)?;
+        for (i, coord) in ring.iter().enumerate() {
+            self.emit(coord, i)?;
+        }
+        self.inner.linestring_end(tagged, idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.polygon_end(tagged, idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.triangle_end(tagged, idx)
+    }
+    forward!(
+        inner => dimensions, multi_dim, supports_curves, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, multilinestring_begin, multilinestring_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        circularstring_begin, circularstring_end, compoundcurve_begin, compoundcurve_end,
+        curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end, multisurface_begin,
+        multisurface_end, polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn winding() {
+        let ccw = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)];
+        assert_eq!(Winding::of_ring(ccw), Some(Winding::CounterClockwise));
+        assert_eq!(
+            Winding::of_ring(ccw.into_iter().rev()),
+            Some(Winding::Clockwise)
+        );
+        // Unclosed ring
+        assert_eq!(
+            Winding::of_ring(ccw[..4].iter().copied()),
+            Some(Winding::CounterClockwise)
+        );
+        assert_eq!(Winding::of_ring([(0.0, 0.0), (1.0, 1.0), (0.0, 0.0)]), None);
+        assert_eq!(Winding::of_ring([]), None);
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn orient_polygons() {
+        use crate::wkt::{Wkt, WktWriter};
+        use crate::GeozeroGeometry;
+
+        let orient = |wkt: &str, exterior: Winding| {
+            let mut out: Vec<u8> = Vec::new();
+            let mut processor = OrientRings::new(WktWriter::new(&mut out), exterior);
+            Wkt(wkt).process_geom(&mut processor).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        let wkt = "MULTIPOLYGON(((0 0,0 10,10 10,10 0,0 0),(2 2,4 2,4 4,2 2)),((20 20,30 20,30 30,20 20)))";
+        assert_eq!(
+            orient(wkt, Winding::CounterClockwise),
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(2 2,4 4,4 2,2 2)),((20 20,30 20,30 30,20 20)))"
+        );
+        assert_eq!(
+            orient(wkt, Winding::Clockwise),
+            "MULTIPOLYGON(((0 0,0 10,10 10,10 0,0 0),(2 2,4 2,4 4,2 2)),((20 20,30 30,30 20,20 20)))"
+        );
+        // Linestrings are passed unchanged
+        let wkt = "GEOMETRYCOLLECTION(LINESTRING(0 0,0 1,1 1,0 0),POLYGON((0 0,0 1,1 1,0 0)))";
+        assert_eq!(
+            orient(wkt, Winding::CounterClockwise),
+            "GEOMETRYCOLLECTION(LINESTRING(0 0,0 1,1 1,0 0),POLYGON((0 0,1 1,0 1,0 0)))"
+        );
+    }
+}
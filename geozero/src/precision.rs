@@ -0,0 +1,71 @@
+/// Number formatting of coordinates in text writers
+///
+/// The default writes the shortest representation which round-trips to the same `f64`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CoordPrecision {
+    /// Number of decimal places, or full precision if `None`
+    pub decimals: Option<usize>,
+    /// Remove trailing zeros and a trailing decimal point
+    pub trim_zeros: bool,
+}
+
+impl CoordPrecision {
+    /// Fixed number of decimal places
+    pub fn decimals(decimals: usize) -> Self {
+        CoordPrecision {
+            decimals: Some(decimals),
+            trim_zeros: false,
+        }
+    }
+
+    /// At most `decimals` decimal places, without trailing zeros
+    pub fn trimmed(decimals: usize) -> Self {
+        CoordPrecision {
+            decimals: Some(decimals),
+            trim_zeros: true,
+        }
+    }
+
+    /// Format a coordinate value
+    pub fn format(&self, value: f64) -> String {
+        let Some(decimals) = self.decimals else {
+            return value.to_string();
+        };
+        let mut s = format!("{value:.decimals$}");
+        if self.trim_zeros && s.contains('.') {
+            let len = s.trim_end_matches('0').trim_end_matches('.').len();
+            s.truncate(len);
+        }
+        if s.starts_with('-') && s[1..].bytes().all(|b| b == b'0' || b == b'.') {
+            // Values rounded to zero
+            s.remove(0);
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn format() {
+        let full = CoordPrecision::default();
+        assert_eq!(full.format(8.530000000000001), "8.530000000000001");
+        assert_eq!(full.format(10.0), "10");
+
+        let fixed = CoordPrecision::decimals(3);
+        assert_eq!(fixed.format(8.530000000000001), "8.530");
+        assert_eq!(fixed.format(10.0), "10.000");
+        assert_eq!(fixed.format(-0.0001), "0.000");
+
+        let trimmed = CoordPrecision::trimmed(3);
+        assert_eq!(trimmed.format(8.530000000000001), "8.53");
+        assert_eq!(trimmed.format(10.0), "10");
+        assert_eq!(trimmed.format(100.0), "100");
+        assert_eq!(trimmed.format(-0.0001), "0");
+        assert_eq!(trimmed.format(-1.23456), "-1.235");
+        assert_eq!(CoordPrecision::trimmed(0).format(2.5), "2");
+        assert_eq!(trimmed.format(f64::NAN), "NaN");
+    }
+}
@@ -0,0 +1,254 @@
+//! CRS reprojection with [proj4rs](https://docs.rs/proj4rs).
+use crate::error::{GeozeroError, Result};
+use crate::{CoordTransform, TransformProcessor};
+use proj4rs::adaptors::transform_xyz;
+use proj4rs::Proj;
+
+/// PROJ string of a built-in EPSG code
+///
+/// Supported are WGS84 (4326), ETRS89 (4258), Web Mercator (3857), LAEA Europe (3035),
+/// Swiss grids (2056, 21781), British National Grid (27700),
+/// WGS84 UTM zones (32601-32660, 32701-32760) and ETRS89 UTM zones (25828-25838).
+pub fn epsg_proj_string(srid: i32) -> Option<String> {
+    let def = match srid {
+        4326 => "+proj=longlat +datum=WGS84 +no_defs",
+        4258 => "+proj=longlat +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +no_defs",
+        3857 => "+proj=merc +a=6378137 +b=6378137 +lat_ts=0 +lon_0=0 +x_0=0 +y_0=0 +k=1 +units=m +nadgrids=@null +no_defs",
+        3035 => "+proj=laea +lat_0=52 +lon_0=10 +x_0=4321000 +y_0=3210000 +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +units=m +no_defs",
+        2056 => "+proj=somerc +lat_0=46.9524055555556 +lon_0=7.43958333333333 +k_0=1 +x_0=2600000 +y_0=1200000 +ellps=bessel +towgs84=674.374,15.056,405.346,0,0,0,0 +units=m +no_defs",
+        21781 => "+proj=somerc +lat_0=46.9524055555556 +lon_0=7.43958333333333 +k_0=1 +x_0=600000 +y_0=200000 +ellps=bessel +towgs84=674.374,15.056,405.346,0,0,0,0 +units=m +no_defs",
+        27700 => "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000 +ellps=airy +towgs84=446.448,-125.157,542.06,0.15,0.247,0.842,-20.489 +units=m +no_defs",
+        32601..=32660 => {
+            return Some(format!(
+                "+proj=utm +zone={} +datum=WGS84 +units=m +no_defs",
+                srid - 32600
+            ))
+        }
+        32701..=32760 => {
+            return Some(format!(
+                "+proj=utm +zone={} +south +datum=WGS84 +units=m +no_defs",
+                srid - 32700
+            ))
+        }
+        25828..=25838 => {
+            return Some(format!(
+                "+proj=utm +zone={} +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +units=m +no_defs",
+                srid - 25800
+            ))
+        }
+        _ => return None,
+    };
+    Some(def.to_string())
+}
+
+fn proj_from_string(def: &str) -> Result<Proj> {
+    Proj::from_proj_string(def).map_err(|e| GeozeroError::Reprojection(format!("`{def}`: {e}")))
+}
+
+fn proj_from_srid(srid: i32) -> Result<Proj> {
+    let def = epsg_proj_string(srid).ok_or(GeozeroError::Srid(srid))?;
+    proj_from_string(&def)
+}
+
+/// Reprojection of coordinates between two coordinate reference systems
+///
+/// Without explicit source, the source CRS is taken from the SRID of the processed stream.
+/// Coordinates following an unset SRID (`srid(None)`) fail to reproject in this mode.
+/// Geographic coordinates are expected and returned in degrees.
+#[derive(Clone)]
+pub struct Reprojection {
+    source: Option<Proj>,
+    fixed_source: bool,
+    target: Proj,
+    target_srid: Option<i32>,
+}
+
+impl Reprojection {
+    /// Reproject to EPSG code `target_srid`, with the source SRID read from the stream
+    pub fn to_srid(target_srid: i32) -> Result<Self> {
+        Ok(Reprojection {
+            source: None,
+            fixed_source: false,
+            target: proj_from_srid(target_srid)?,
+            target_srid: Some(target_srid),
+        })
+    }
+
+    /// Reproject to a CRS given as PROJ string, with the source SRID read from the stream
+    pub fn to_proj_string(target: &str, target_srid: Option<i32>) -> Result<Self> {
+        Ok(Reprojection {
+            source: None,
+            fixed_source: false,
+            target: proj_from_string(target)?,
+            target_srid,
+        })
+    }
+
+    /// Use EPSG code `srid` as source, ignoring the SRID of the stream
+    pub fn with_source_srid(self, srid: i32) -> Result<Self> {
+        Ok(Reprojection {
+            source: Some(proj_from_srid(srid)?),
+            fixed_source: true,
+            ..self
+        })
+    }
+
+    /// Use a PROJ string as source, ignoring the SRID of the stream
+    pub fn with_source_proj_string(self, source: &str) -> Result<Self> {
+        Ok(Reprojection {
+            source: Some(proj_from_string(source)?),
+            fixed_source: true,
+            ..self
+        })
+    }
+}
+
+impl CoordTransform for Reprojection {
+    fn transform(&mut self, x: f64, y: f64, z: Option<f64>) -> Result<(f64, f64, Option<f64>)> {
+        let source = self.source.as_ref().ok_or_else(|| {
+            GeozeroError::Reprojection("source SRID missing in input".to_string())
+        })?;
+        let (x, y) = if source.is_latlong() {
+            (x.to_radians(), y.to_radians())
+        } else {
+            (x, y)
+        };
+        let (x, y, tz) = transform_xyz(source, &self.target, x, y, z.unwrap_or(0.0))
+            .map_err(|e| GeozeroError::Reprojection(e.to_string()))?;
+        let (x, y) = if self.target.is_latlong() {
+            (x.to_degrees(), y.to_degrees())
+        } else {
+            (x, y)
+        };
+        Ok((x, y, z.map(|_| tz)))
+    }
+    fn transform_srid(&mut self, srid: Option<i32>) -> Result<Option<i32>> {
+        if !self.fixed_source {
+            // A missing SRID must not reuse the source CRS of a previous geometry
+            self.source = srid.map(proj_from_srid).transpose()?;
+        }
+        Ok(self.target_srid)
+    }
+}
+
+/// Processor reprojecting all coordinates
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::proj::{ReprojectProcessor, Reprojection};
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::GeozeroGeometry;
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let reprojection = Reprojection::to_srid(32632).unwrap().with_source_srid(4326).unwrap();
+/// let mut processor = ReprojectProcessor::new(WktWriter::new(&mut out), reprojection);
+/// Wkt("POINT(9 0)").process_geom(&mut processor).unwrap();
+/// # }
+/// ```
+pub type ReprojectProcessor<P> = TransformProcessor<P, Reprojection>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{GeomProcessor, GeozeroGeometry, RecordedGeometry};
+
+    fn reproject_point(
+        reprojection: Reprojection,
+        srid: Option<i32>,
+        x: f64,
+        y: f64,
+    ) -> Result<RecordedGeometry> {
+        let mut processor = ReprojectProcessor::new(RecordedGeometry::default(), reprojection);
+        processor.srid(srid)?;
+        processor.point_begin(0)?;
+        processor.xy(x, y, 0)?;
+        processor.point_end(0)?;
+        Ok(processor.into_inner())
+    }
+
+    fn assert_point(geom: &RecordedGeometry, x: f64, y: f64, tolerance: f64) {
+        let bbox = geom.bbox().unwrap().unwrap();
+        assert!(
+            (bbox.minx - x).abs() < tolerance && (bbox.miny - y).abs() < tolerance,
+            "({}, {}) != ({x}, {y})",
+            bbox.minx,
+            bbox.miny
+        );
+    }
+
+    #[test]
+    fn explicit_source() {
+        let utm = Reprojection::to_srid(32632)
+            .unwrap()
+            .with_source_srid(4326)
+            .unwrap();
+        let geom = reproject_point(utm.clone(), None, 9.0, 0.0).unwrap();
+        assert_point(&geom, 500000.0, 0.0, 1e-6);
+        assert_eq!(geom.srid(), Some(32632));
+
+        // Stream SRID is ignored
+        let geom = reproject_point(utm, Some(3857), 9.0, 0.0).unwrap();
+        assert_point(&geom, 500000.0, 0.0, 1e-6);
+    }
+
+    #[test]
+    fn stream_source() {
+        let to_wgs84 = Reprojection::to_srid(4326).unwrap();
+        let geom = reproject_point(to_wgs84.clone(), Some(2056), 2600000.0, 1200000.0).unwrap();
+        assert_point(&geom, 7.4386, 46.9511, 1e-3);
+        assert_eq!(geom.srid(), Some(4326));
+
+        let (x, y) = crate::mercator::lonlat_to_mercator(8.5, 47.4);
+        let geom = reproject_point(to_wgs84.clone(), Some(3857), x, y).unwrap();
+        assert_point(&geom, 8.5, 47.4, 1e-9);
+
+        assert!(matches!(
+            reproject_point(to_wgs84.clone(), None, 0.0, 0.0),
+            Err(GeozeroError::Reprojection(_))
+        ));
+        assert!(matches!(
+            reproject_point(to_wgs84, Some(1234), 0.0, 0.0),
+            Err(GeozeroError::Srid(1234))
+        ));
+    }
+
+    #[test]
+    fn stream_source_reset() {
+        let mut processor = ReprojectProcessor::new(
+            RecordedGeometry::default(),
+            Reprojection::to_srid(4326).unwrap(),
+        );
+        processor.srid(Some(2056)).unwrap();
+        processor.point_begin(0).unwrap();
+        processor.xy(2600000.0, 1200000.0, 0).unwrap();
+        processor.point_end(0).unwrap();
+
+        // Source CRS of the previous geometry is not reused
+        processor.srid(None).unwrap();
+        processor.point_begin(0).unwrap();
+        assert!(matches!(
+            processor.xy(2600000.0, 1200000.0, 0),
+            Err(GeozeroError::Reprojection(_))
+        ));
+    }
+
+    #[test]
+    fn z_values() {
+        let reprojection = Reprojection::to_srid(3857)
+            .unwrap()
+            .with_source_srid(4326)
+            .unwrap();
+        let mut processor = ReprojectProcessor::new(RecordedGeometry::default(), reprojection);
+        processor.point_begin(0).unwrap();
+        processor
+            .coordinate(0.0, 0.0, Some(100.0), None, None, None, 0)
+            .unwrap();
+        processor.point_end(0).unwrap();
+        let mut bounds = crate::BoundsProcessor::new();
+        processor.into_inner().process_geom(&mut bounds).unwrap();
+        assert_eq!(bounds.bounds().unwrap().z, Some((100.0, 100.0)));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::error::{GeozeroError, Result};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::hash::BuildHasher;
 
@@ -23,6 +23,34 @@ pub enum ColumnValue<'a> {
     /// A datetime stored as an ISO8601-formatted string
     DateTime(&'a str),
     Binary(&'a [u8]),
+    /// A missing value
+    Null,
+}
+
+/// Owned feature property value.
+///
+/// Mirrors [`ColumnValue`] for storing property values beyond the lifetime of the processed feature.
+#[derive(Clone, PartialEq, Debug)]
+pub enum OwnedColumnValue {
+    Byte(i8),
+    UByte(u8),
+    Bool(bool),
+    Short(i16),
+    UShort(u16),
+    Int(i32),
+    UInt(u32),
+    Long(i64),
+    ULong(u64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    /// A JSON-formatted string
+    Json(String),
+    /// A datetime stored as an ISO8601-formatted string
+    DateTime(String),
+    Binary(Vec<u8>),
+    /// A missing value
+    Null,
 }
 
 /// Feature property processing trait.
@@ -80,6 +108,116 @@ impl fmt::Display for ColumnValue<'_> {
                 write!(f, "{v}")
             }
             ColumnValue::Binary(_v) => write!(f, "[BINARY]"),
+            ColumnValue::Null => Ok(()),
+        }
+    }
+}
+
+impl From<&ColumnValue<'_>> for OwnedColumnValue {
+    fn from(v: &ColumnValue) -> Self {
+        match *v {
+            ColumnValue::Byte(v) => OwnedColumnValue::Byte(v),
+            ColumnValue::UByte(v) => OwnedColumnValue::UByte(v),
+            ColumnValue::Bool(v) => OwnedColumnValue::Bool(v),
+            ColumnValue::Short(v) => OwnedColumnValue::Short(v),
+            ColumnValue::UShort(v) => OwnedColumnValue::UShort(v),
+            ColumnValue::Int(v) => OwnedColumnValue::Int(v),
+            ColumnValue::UInt(v) => OwnedColumnValue::UInt(v),
+            ColumnValue::Long(v) => OwnedColumnValue::Long(v),
+            ColumnValue::ULong(v) => OwnedColumnValue::ULong(v),
+            ColumnValue::Float(v) => OwnedColumnValue::Float(v),
+            ColumnValue::Double(v) => OwnedColumnValue::Double(v),
+            ColumnValue::String(v) => OwnedColumnValue::String(v.to_string()),
+            ColumnValue::Json(v) => OwnedColumnValue::Json(v.to_string()),
+            ColumnValue::DateTime(v) => OwnedColumnValue::DateTime(v.to_string()),
+            ColumnValue::Binary(v) => OwnedColumnValue::Binary(v.to_vec()),
+            ColumnValue::Null => OwnedColumnValue::Null,
+        }
+    }
+}
+
+impl From<ColumnValue<'_>> for OwnedColumnValue {
+    fn from(v: ColumnValue) -> Self {
+        (&v).into()
+    }
+}
+
+impl<'a> From<&'a OwnedColumnValue> for ColumnValue<'a> {
+    fn from(v: &'a OwnedColumnValue) -> Self {
+        match v {
+            OwnedColumnValue::Byte(v) => ColumnValue::Byte(*v),
+            OwnedColumnValue::UByte(v) => ColumnValue::UByte(*v),
+            OwnedColumnValue::Bool(v) => ColumnValue::Bool(*v),
+            OwnedColumnValue::Short(v) => ColumnValue::Short(*v),
+            OwnedColumnValue::UShort(v) => ColumnValue::UShort(*v),
+            OwnedColumnValue::Int(v) => ColumnValue::Int(*v),
+            OwnedColumnValue::UInt(v) => ColumnValue::UInt(*v),
+            OwnedColumnValue::Long(v) => ColumnValue::Long(*v),
+            OwnedColumnValue::ULong(v) => ColumnValue::ULong(*v),
+            OwnedColumnValue::Float(v) => ColumnValue::Float(*v),
+            OwnedColumnValue::Double(v) => ColumnValue::Double(*v),
+            OwnedColumnValue::String(v) => ColumnValue::String(v),
+            OwnedColumnValue::Json(v) => ColumnValue::Json(v),
+            OwnedColumnValue::DateTime(v) => ColumnValue::DateTime(v),
+            OwnedColumnValue::Binary(v) => ColumnValue::Binary(v),
+            OwnedColumnValue::Null => ColumnValue::Null,
+        }
+    }
+}
+
+impl OwnedColumnValue {
+    /// Borrow as [`ColumnValue`], e.g. for passing to a [`PropertyProcessor`].
+    pub fn as_column_value(&self) -> ColumnValue<'_> {
+        self.into()
+    }
+
+    /// Numeric value, parsing strings. `None` for other types.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            OwnedColumnValue::Byte(v) => Some(f64::from(*v)),
+            OwnedColumnValue::UByte(v) => Some(f64::from(*v)),
+            OwnedColumnValue::Short(v) => Some(f64::from(*v)),
+            OwnedColumnValue::UShort(v) => Some(f64::from(*v)),
+            OwnedColumnValue::Int(v) => Some(f64::from(*v)),
+            OwnedColumnValue::UInt(v) => Some(f64::from(*v)),
+            OwnedColumnValue::Long(v) => Some(*v as f64),
+            OwnedColumnValue::ULong(v) => Some(*v as f64),
+            OwnedColumnValue::Float(v) => Some(f64::from(*v)),
+            OwnedColumnValue::Double(v) => Some(*v),
+            OwnedColumnValue::String(v) => v.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for OwnedColumnValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_column_value().fmt(f)
+    }
+}
+
+impl From<&ColumnValue<'_>> for serde_json::Value {
+    fn from(v: &ColumnValue) -> Self {
+        use serde_json::Value;
+        match *v {
+            ColumnValue::Byte(v) => Value::from(v),
+            ColumnValue::UByte(v) => Value::from(v),
+            ColumnValue::Bool(v) => Value::from(v),
+            ColumnValue::Short(v) => Value::from(v),
+            ColumnValue::UShort(v) => Value::from(v),
+            ColumnValue::Int(v) => Value::from(v),
+            ColumnValue::UInt(v) => Value::from(v),
+            ColumnValue::Long(v) => Value::from(v),
+            ColumnValue::ULong(v) => Value::from(v),
+            // Non-finite numbers are not representable in JSON and are converted to `null`
+            ColumnValue::Float(v) => Value::from(v),
+            ColumnValue::Double(v) => Value::from(v),
+            ColumnValue::String(v) | ColumnValue::DateTime(v) => Value::from(v),
+            ColumnValue::Json(v) => {
+                serde_json::from_str(v).unwrap_or_else(|_| Value::String(v.to_string()))
+            }
+            ColumnValue::Binary(v) => Value::from(v),
+            ColumnValue::Null => Value::Null,
         }
     }
 }
@@ -133,6 +271,8 @@ macro_rules! impl_scalar_property_reader {
             fn from(v: &ColumnValue) -> Result<$t> {
                 if let $e(v) = v {
                     Ok(*v)
+                } else if let ColumnValue::Null = v {
+                    Err(GeozeroError::ColumnNotFound)
                 } else {
                     Err(GeozeroError::ColumnType(stringify!($e).to_string(), format!("{v:?}")))
                 }
@@ -160,7 +300,11 @@ impl_scalar_property_reader!(f64, ColumnValue::Double);
 
 impl From<&ColumnValue<'_>> for Result<String> {
     fn from(v: &ColumnValue) -> Result<String> {
-        Ok(v.to_string())
+        if let ColumnValue::Null = v {
+            Err(GeozeroError::ColumnNotFound)
+        } else {
+            Ok(v.to_string())
+        }
     }
 }
 
@@ -170,9 +314,39 @@ impl PropertyReadType for String {
     }
 }
 
+impl PropertyReadType for OwnedColumnValue {
+    fn get_value(v: &ColumnValue) -> Result<OwnedColumnValue> {
+        Ok(v.into())
+    }
+}
+
 impl<S: BuildHasher> PropertyProcessor for HashMap<String, String, S> {
     fn property(&mut self, _idx: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
-        self.insert(colname.to_string(), colval.to_string());
+        // NULL values are omitted, like with `FeatureProperties::property`
+        if *colval != ColumnValue::Null {
+            self.insert(colname.to_string(), colval.to_string());
+        }
+        Ok(false)
+    }
+}
+
+impl PropertyProcessor for BTreeMap<String, OwnedColumnValue> {
+    fn property(&mut self, _idx: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
+        self.insert(colname.to_string(), colval.into());
+        Ok(false)
+    }
+}
+
+impl PropertyProcessor for Vec<(String, OwnedColumnValue)> {
+    fn property(&mut self, _idx: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
+        self.push((colname.to_string(), colval.into()));
+        Ok(false)
+    }
+}
+
+impl PropertyProcessor for serde_json::Map<String, serde_json::Value> {
+    fn property(&mut self, _idx: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
+        self.insert(colname.to_string(), colval.into());
         Ok(false)
     }
 }
@@ -189,8 +363,53 @@ fn convert_column_value() {
 
     let v = &ColumnValue::String("Yes");
     assert_eq!(Result::<String>::from(v).unwrap(), "Yes".to_string());
+    assert_eq!(
+        Result::<i32>::from(&ColumnValue::Null)
+            .unwrap_err()
+            .to_string(),
+        "column not found or null"
+    );
+    assert!(Result::<String>::from(&ColumnValue::Null).is_err());
     assert_eq!(
         Result::<i32>::from(v).unwrap_err().to_string(),
         r#"expected a `ColumnValue::Int` value but found `String("Yes")`"#
     );
 }
+
+#[test]
+fn owned_column_value() {
+    let v = ColumnValue::Double(1.5);
+    let owned = OwnedColumnValue::from(&v);
+    assert_eq!(owned, OwnedColumnValue::Double(1.5));
+    assert_eq!(owned.as_column_value(), v);
+
+    let v = ColumnValue::Binary(&[1, 2]);
+    let owned = OwnedColumnValue::from(&v);
+    assert_eq!(owned, OwnedColumnValue::Binary(vec![1, 2]));
+    assert_eq!(ColumnValue::from(&owned), v);
+
+    let mut props: Vec<(String, OwnedColumnValue)> = Vec::new();
+    props
+        .property(0, "name", &ColumnValue::String("x"))
+        .unwrap();
+    props.property(1, "pop", &ColumnValue::Long(42)).unwrap();
+    assert_eq!(
+        props,
+        vec![
+            (
+                "name".to_string(),
+                OwnedColumnValue::String("x".to_string())
+            ),
+            ("pop".to_string(), OwnedColumnValue::Long(42)),
+        ]
+    );
+
+    let mut json = serde_json::Map::new();
+    json.property(0, "pop", &ColumnValue::Long(42)).unwrap();
+    json.property(1, "tags", &ColumnValue::Json(r#"["a"]"#))
+        .unwrap();
+    assert_eq!(
+        serde_json::Value::Object(json),
+        serde_json::json!({"pop": 42, "tags": ["a"]})
+    );
+}
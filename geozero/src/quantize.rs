@@ -0,0 +1,317 @@
+use crate::coord::Coord;
+use crate::error::{GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::PropertyProcessor;
+
+/// Processor snapping XY coordinates to a grid
+///
+/// Consecutive duplicate vertices of linestrings and polygon rings created by snapping are
+/// removed and passed on with the reduced size. A linestring collapsing to a single point keeps
+/// two points. Interior rings left with fewer than 4 points are dropped. A polygon whose exterior
+/// ring collapses is dropped from multipolygons and passed as empty polygon otherwise.
+/// Circular strings are snapped without removing vertices.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::{GeozeroGeometry, QuantizeProcessor};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = QuantizeProcessor::new(WktWriter::new(&mut out), 0.01).unwrap();
+/// Wkt("LINESTRING(8.5300001 1,8.531 1.001,9.12345 2)").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "LINESTRING(8.53 1,9.12 2)");
+/// # }
+/// ```
+pub struct QuantizeProcessor<P> {
+    inner: P,
+    grid_size: f64,
+    /// Coordinates of the current linestring
+    line: Option<Vec<Coord>>,
+    /// Rings of the current polygon
+    rings: Option<Vec<Vec<Coord>>>,
+    /// Polygons of the current multipolygon
+    polygons: Option<Vec<Vec<Vec<Coord>>>>,
+}
+
+impl<P: GeomProcessor> QuantizeProcessor<P> {
+    /// Snap to a grid with cells of `grid_size`, which has to be positive
+    pub fn new(inner: P, grid_size: f64) -> Result<Self> {
+        if !(grid_size > 0.0 && grid_size.is_finite()) {
+            return Err(GeozeroError::Geometry(format!(
+                "invalid grid size {grid_size}"
+            )));
+        }
+        Ok(QuantizeProcessor {
+            inner,
+            grid_size,
+            line: None,
+            rings: None,
+            polygons: None,
+        })
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn snap(&self, value: f64) -> f64 {
+        let scale = 1.0 / self.grid_size;
+        let snapped = if scale.fract() == 0.0 {
+            // Dividing by the scale avoids artifacts like 8.530000000000001 for decimal grids
+            (value * scale).round() / scale
+        } else {
+            (value / self.grid_size).round() * self.grid_size
+        };
+        // Avoid negative zero
+        snapped + 0.0
+    }
+
+    fn coord(&mut self, mut coord: Coord, idx: usize) -> Result<()> {
+        coord.x = self.snap(coord.x);
+        coord.y = self.snap(coord.y);
+        match &mut self.line {
+            Some(line) => {
+                let duplicate = line
+                    .last()
+                    .is_some_and(|c| c.x == coord.x && c.y == coord.y && c.z == coord.z);
+                if !duplicate {
+                    line.push(coord);
+                }
+                Ok(())
+            }
+            None => self.emit(&coord, idx),
+        }
+    }
+
+    fn emit(&mut self, c: &Coord, idx: usize) -> Result<()> {
+        if self.inner.multi_dim() {
+            self.inner.coordinate(c.x, c.y, c.z, c.m, c.t, c.tm, idx)
+        } else {
+            self.inner.xy(c.x, c.y, idx)
+        }
+    }
+
+    fn emit_line(&mut self, coords: &[Coord], tagged: bool, idx: usize) -> Result<()> {
+        self.inner.linestring_begin(tagged, coords.len(), idx)?;
+        for (i, coord) in coords.iter().enumerate() {
+            self.emit(coord, i)?;
+        }
+        self.inner.linestring_end(tagged, idx)
+    }
+
+    fn emit_polygon(&mut self, rings: &[Vec<Coord>], tagged: bool, idx: usize) -> Result<()> {
+        self.inner.polygon_begin(tagged, rings.len(), idx)?;
+        for (i, ring) in rings.iter().enumerate() {
+            self.emit_line(ring, false, i)?;
+        }
+        self.inner.polygon_end(tagged, idx)
+    }
+}
+
+/// Rings of a polygon without collapsed rings, empty if the exterior ring collapsed
+fn valid_rings(mut rings: Vec<Vec<Coord>>) -> Vec<Vec<Coord>> {
+    if rings.first().is_some_and(|exterior| exterior.len() < 4) {
+        return Vec::new();
+    }
+    rings.retain(|ring| ring.len() >= 4);
+    rings
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for QuantizeProcessor<P> {
+    forward!(
+        inner => dataset_begin, schema, dataset_end, feature_begin, feature_end, properties_begin,
+        properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for QuantizeProcessor<P> {
+    forward!(inner => property);
+}
+
+impl<P: GeomProcessor> GeomProcessor for QuantizeProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let coord = Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+            t: None,
+            tm: None,
+        };
+        self.coord(coord, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let coord = Coord { x, y, z, m, t, tm };
+        self.coord(coord, idx)
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.line = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        let mut coords = self.line.take().unwrap_or_default();
+        if let Some(rings) = &mut self.rings {
+            rings.push(coords);
+            return Ok(());
+        }
+        if coords.len() == 1 {
+            coords.push(coords[0]);
+        }
+        self.emit_line(&coords, tagged, idx)
+    }
+    fn polygon_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.rings = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        let rings = valid_rings(self.rings.take().unwrap_or_default());
+        match &mut self.polygons {
+            Some(polygons) => {
+                if !rings.is_empty() {
+                    polygons.push(rings);
+                }
+                Ok(())
+            }
+            None => self.emit_polygon(&rings, tagged, idx),
+        }
+    }
+    fn multipolygon_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.polygons = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        let polygons = self.polygons.take().unwrap_or_default();
+        self.inner.multipolygon_begin(polygons.len(), idx)?;
+        for (i, rings) in polygons.iter().enumerate() {
+            self.emit_polygon(rings, false, i)?;
+        }
+        self.inner.multipolygon_end(idx)
+    }
+    forward!(
+        inner => dimensions, multi_dim, supports_curves, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, multilinestring_begin, multilinestring_end,
+        geometrycollection_begin, geometrycollection_end, circularstring_begin, circularstring_end,
+        compoundcurve_begin, compoundcurve_end, curvepolygon_begin, curvepolygon_end,
+        multicurve_begin, multicurve_end, multisurface_begin, multisurface_end, triangle_begin,
+        triangle_end, polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "with-wkt")]
+    fn quantize(wkt: &str, grid_size: f64) -> String {
+        use crate::wkt::{Wkt, WktWriter};
+        use crate::GeozeroGeometry;
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = QuantizeProcessor::new(WktWriter::new(&mut out), grid_size).unwrap();
+        Wkt(wkt).process_geom(&mut processor).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn snap_to_grid() {
+        assert_eq!(
+            quantize("POINT(8.530000000000001 -0.004)", 0.01),
+            "POINT(8.53 0)"
+        );
+        assert_eq!(quantize("POINT(12 17)", 5.0), "POINT(10 15)");
+        assert_eq!(quantize("POINT(0.7 0.2)", 0.25), "POINT(0.75 0.25)");
+        assert_eq!(
+            quantize("LINESTRING(0 0,0.1 0.1,1 1,0.9 1.1,2 2)", 1.0),
+            "LINESTRING(0 0,1 1,2 2)"
+        );
+        assert_eq!(
+            quantize("LINESTRING(0 0,0.1 0.1)", 1.0),
+            "LINESTRING(0 0,0 0)"
+        );
+        assert_eq!(
+            quantize("POLYGON((0 0,10 0,10.2 0.1,10 10,0 10,0 0))", 1.0),
+            "POLYGON((0 0,10 0,10 10,0 10,0 0))"
+        );
+        // Only consecutive duplicates are removed
+        assert_eq!(
+            quantize("MULTIPOINT(0 0,0.1 0.1)", 1.0),
+            "MULTIPOINT(0 0,0 0)"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn collapsed_rings() {
+        // Collapsed interior ring is dropped
+        assert_eq!(
+            quantize(
+                "POLYGON((0 0,10 0,10 10,0 10,0 0),(5 5,5.1 5,5.1 5.1,5 5))",
+                1.0
+            ),
+            "POLYGON((0 0,10 0,10 10,0 10,0 0))"
+        );
+        // Polygon with collapsed exterior ring is dropped from multipolygons
+        assert_eq!(
+            quantize(
+                "MULTIPOLYGON(((0 0,0.1 0,0.1 0.1,0 0)),((0 0,10 0,10 10,0 0)))",
+                1.0
+            ),
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 0)))"
+        );
+        assert_eq!(
+            quantize("POLYGON((0 0,0.1 0,0.1 0.1,0 0))", 1.0),
+            "POLYGON EMPTY"
+        );
+        // Ring with 3 points left has no area
+        assert_eq!(
+            quantize("POLYGON((0 0,1 0,1.1 0.1,0 0))", 1.0),
+            "POLYGON EMPTY"
+        );
+    }
+
+    #[test]
+    fn invalid_grid_size() {
+        let geom = crate::RecordedGeometry::new();
+        assert!(QuantizeProcessor::new(geom.clone(), 0.0).is_err());
+        assert!(QuantizeProcessor::new(geom.clone(), -1.0).is_err());
+        assert!(QuantizeProcessor::new(geom, f64::NAN).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "with-geojson")]
+    fn sizes_and_dimensions() {
+        use crate::geojson::GeoJson;
+        use crate::{
+            CoordDimensions, GeozeroGeometry, RecordedGeometry, ToWkt, ValidatingProcessor,
+        };
+
+        let geojson = GeoJson(
+            r#"{"type": "MultiLineString", "coordinates": [[[0,0,1],[0.1,0,1],[0.2,0,2]],[[0,0,0],[0.3,0.3,0]]]}"#,
+        );
+        let mut processor =
+            QuantizeProcessor::new(ValidatingProcessor::new(RecordedGeometry::new()), 1.0).unwrap();
+        geojson.process_geom(&mut processor).unwrap();
+        let mut validator = processor.into_inner();
+        validator.finish().unwrap();
+        let geom = validator.into_inner();
+        assert_eq!(
+            geom.to_wkt_ndim(CoordDimensions::xyz()).unwrap(),
+            "MULTILINESTRING((0 0 1,0 0 2),(0 0 0,0 0 0))"
+        );
+    }
+}
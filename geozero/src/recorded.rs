@@ -0,0 +1,785 @@
+use crate::error::{GeozeroError, Result};
+use crate::{
+    ArcTolerance, ColumnValue, CoordDimensions, FeatureProcessor, FeatureProperties, GeomProcessor,
+    GeozeroGeometry, LinearizeProcessor, OwnedColumnValue, ProcessorRef, PropertyProcessor,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+enum GeomEvent {
+    Srid(Option<i32>),
+    /// Run of `count` XY coordinates with consecutive indices
+    Xy {
+        first_idx: usize,
+        count: usize,
+    },
+    /// Run of `count` coordinates with consecutive indices and the same dimensions
+    Coordinate {
+        dims: CoordDims,
+        first_idx: usize,
+        count: usize,
+    },
+    EmptyPoint(usize),
+    PointBegin(usize),
+    PointEnd(usize),
+    MultiPointBegin(usize, usize),
+    MultiPointEnd(usize),
+    LineStringBegin(bool, usize, usize),
+    LineStringEnd(bool, usize),
+    MultiLineStringBegin(usize, usize),
+    MultiLineStringEnd(usize),
+    PolygonBegin(bool, usize, usize),
+    PolygonEnd(bool, usize),
+    MultiPolygonBegin(usize, usize),
+    MultiPolygonEnd(usize),
+    GeometryCollectionBegin(usize, usize),
+    GeometryCollectionEnd(usize),
+    CircularStringBegin(usize, usize),
+    CircularStringEnd(usize),
+    CompoundCurveBegin(usize, usize),
+    CompoundCurveEnd(usize),
+    CurvePolygonBegin(usize, usize),
+    CurvePolygonEnd(usize),
+    MultiCurveBegin(usize, usize),
+    MultiCurveEnd(usize),
+    MultiSurfaceBegin(usize, usize),
+    MultiSurfaceEnd(usize),
+    TriangleBegin(bool, usize, usize),
+    TriangleEnd(bool, usize),
+    PolyhedralSurfaceBegin(usize, usize),
+    PolyhedralSurfaceEnd(usize),
+    TinBegin(usize, usize),
+    TinEnd(usize),
+}
+
+/// Optional ordinates present in a recorded coordinate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct CoordDims {
+    z: bool,
+    m: bool,
+    t: bool,
+    tm: bool,
+}
+
+/// Owned geometry, stored as recorded [`GeomProcessor`] events
+///
+/// Coordinates are kept in a flat buffer, with runs of consecutive coordinates stored as a single event.
+/// Recording requests all coordinate dimensions from the source.
+/// Replaying emits `xy` or `coordinate`, depending on the dimensions requested by the target processor.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecordedGeometry {
+    events: Vec<GeomEvent>,
+    /// x, y and present z, m, t values of all coordinates
+    ordinates: Vec<f64>,
+    /// tm values of coordinates having one
+    tms: Vec<u64>,
+}
+
+impl RecordedGeometry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// No geometry events recorded
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Remove all recorded events
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.ordinates.clear();
+        self.tms.clear();
+    }
+
+    /// Number of recorded coordinates
+    pub fn num_coords(&self) -> usize {
+        self.events
+            .iter()
+            .map(|event| match event {
+                GeomEvent::Xy { count, .. } | GeomEvent::Coordinate { count, .. } => *count,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn replay<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        let multi_dim = processor.multi_dim();
+        let mut ordinates = self.ordinates.iter().copied();
+        let mut tms = self.tms.iter().copied();
+        for event in &self.events {
+            match *event {
+                GeomEvent::Srid(srid) => processor.srid(srid)?,
+                GeomEvent::Xy { first_idx, count } => {
+                    for idx in first_idx..first_idx + count {
+                        let (x, y) = (next(&mut ordinates)?, next(&mut ordinates)?);
+                        if multi_dim {
+                            processor.coordinate(x, y, None, None, None, None, idx)?;
+                        } else {
+                            processor.xy(x, y, idx)?;
+                        }
+                    }
+                }
+                GeomEvent::Coordinate {
+                    dims,
+                    first_idx,
+                    count,
+                } => {
+                    for idx in first_idx..first_idx + count {
+                        let (x, y) = (next(&mut ordinates)?, next(&mut ordinates)?);
+                        let z = dims.z.then(|| next(&mut ordinates)).transpose()?;
+                        let m = dims.m.then(|| next(&mut ordinates)).transpose()?;
+                        let t = dims.t.then(|| next(&mut ordinates)).transpose()?;
+                        let tm = dims.tm.then(|| next(&mut tms)).transpose()?;
+                        if multi_dim {
+                            processor.coordinate(x, y, z, m, t, tm, idx)?;
+                        } else {
+                            processor.xy(x, y, idx)?;
+                        }
+                    }
+                }
+                GeomEvent::EmptyPoint(idx) => processor.empty_point(idx)?,
+                GeomEvent::PointBegin(idx) => processor.point_begin(idx)?,
+                GeomEvent::PointEnd(idx) => processor.point_end(idx)?,
+                GeomEvent::MultiPointBegin(size, idx) => processor.multipoint_begin(size, idx)?,
+                GeomEvent::MultiPointEnd(idx) => processor.multipoint_end(idx)?,
+                GeomEvent::LineStringBegin(tagged, size, idx) => {
+                    processor.linestring_begin(tagged, size, idx)?;
+                }
+                GeomEvent::LineStringEnd(tagged, idx) => processor.linestring_end(tagged, idx)?,
+                GeomEvent::MultiLineStringBegin(size, idx) => {
+                    processor.multilinestring_begin(size, idx)?;
+                }
+                GeomEvent::MultiLineStringEnd(idx) => processor.multilinestring_end(idx)?,
+                GeomEvent::PolygonBegin(tagged, size, idx) => {
+                    processor.polygon_begin(tagged, size, idx)?;
+                }
+                GeomEvent::PolygonEnd(tagged, idx) => processor.polygon_end(tagged, idx)?,
+                GeomEvent::MultiPolygonBegin(size, idx) => {
+                    processor.multipolygon_begin(size, idx)?;
+                }
+                GeomEvent::MultiPolygonEnd(idx) => processor.multipolygon_end(idx)?,
+                GeomEvent::GeometryCollectionBegin(size, idx) => {
+                    processor.geometrycollection_begin(size, idx)?;
+                }
+                GeomEvent::GeometryCollectionEnd(idx) => processor.geometrycollection_end(idx)?,
+                GeomEvent::CircularStringBegin(size, idx) => {
+                    processor.circularstring_begin(size, idx)?;
+                }
+                GeomEvent::CircularStringEnd(idx) => processor.circularstring_end(idx)?,
+                GeomEvent::CompoundCurveBegin(size, idx) => {
+                    processor.compoundcurve_begin(size, idx)?;
+                }
+                GeomEvent::CompoundCurveEnd(idx) => processor.compoundcurve_end(idx)?,
+                GeomEvent::CurvePolygonBegin(size, idx) => {
+                    processor.curvepolygon_begin(size, idx)?;
+                }
+                GeomEvent::CurvePolygonEnd(idx) => processor.curvepolygon_end(idx)?,
+                GeomEvent::MultiCurveBegin(size, idx) => processor.multicurve_begin(size, idx)?,
+                GeomEvent::MultiCurveEnd(idx) => processor.multicurve_end(idx)?,
+                GeomEvent::MultiSurfaceBegin(size, idx) => {
+                    processor.multisurface_begin(size, idx)?;
+                }
+                GeomEvent::MultiSurfaceEnd(idx) => processor.multisurface_end(idx)?,
+                GeomEvent::TriangleBegin(tagged, size, idx) => {
+                    processor.triangle_begin(tagged, size, idx)?;
+                }
+                GeomEvent::TriangleEnd(tagged, idx) => processor.triangle_end(tagged, idx)?,
+                GeomEvent::PolyhedralSurfaceBegin(size, idx) => {
+                    processor.polyhedralsurface_begin(size, idx)?;
+                }
+                GeomEvent::PolyhedralSurfaceEnd(idx) => processor.polyhedralsurface_end(idx)?,
+                GeomEvent::TinBegin(size, idx) => processor.tin_begin(size, idx)?,
+                GeomEvent::TinEnd(idx) => processor.tin_end(idx)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl GeozeroGeometry for RecordedGeometry {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        if processor.supports_curves() {
+            self.replay(processor)
+        } else {
+            self.replay(&mut LinearizeProcessor::new(
+                ProcessorRef(processor),
+                ArcTolerance::default(),
+            ))
+        }
+    }
+    fn srid(&self) -> Option<i32> {
+        self.events.iter().find_map(|event| match event {
+            GeomEvent::Srid(srid) => *srid,
+            _ => None,
+        })
+    }
+}
+
+fn next<T>(values: &mut impl Iterator<Item = T>) -> Result<T> {
+    values.next().ok_or(GeozeroError::Coord)
+}
+
+impl GeomProcessor for RecordedGeometry {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions {
+            z: true,
+            m: true,
+            t: true,
+            tm: true,
+        }
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.events.push(GeomEvent::Srid(srid));
+        Ok(())
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.ordinates.extend([x, y]);
+        match self.events.last_mut() {
+            Some(GeomEvent::Xy { first_idx, count }) if *first_idx + *count == idx => *count += 1,
+            _ => self.events.push(GeomEvent::Xy {
+                first_idx: idx,
+                count: 1,
+            }),
+        }
+        Ok(())
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let dims = CoordDims {
+            z: z.is_some(),
+            m: m.is_some(),
+            t: t.is_some(),
+            tm: tm.is_some(),
+        };
+        if !(dims.z || dims.m || dims.t || dims.tm) {
+            return self.xy(x, y, idx);
+        }
+        self.ordinates
+            .extend([Some(x), Some(y), z, m, t].into_iter().flatten());
+        self.tms.extend(tm);
+        match self.events.last_mut() {
+            Some(GeomEvent::Coordinate {
+                dims: run_dims,
+                first_idx,
+                count,
+            }) if *run_dims == dims && *first_idx + *count == idx => *count += 1,
+            _ => self.events.push(GeomEvent::Coordinate {
+                dims,
+                first_idx: idx,
+                count: 1,
+            }),
+        }
+        Ok(())
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::EmptyPoint(idx));
+        Ok(())
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::PointBegin(idx));
+        Ok(())
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::PointEnd(idx));
+        Ok(())
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::MultiPointBegin(size, idx));
+        Ok(())
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::MultiPointEnd(idx));
+        Ok(())
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.events
+            .push(GeomEvent::LineStringBegin(tagged, size, idx));
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::LineStringEnd(tagged, idx));
+        Ok(())
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::MultiLineStringBegin(size, idx));
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::MultiLineStringEnd(idx));
+        Ok(())
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::PolygonBegin(tagged, size, idx));
+        Ok(())
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::PolygonEnd(tagged, idx));
+        Ok(())
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::MultiPolygonBegin(size, idx));
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::MultiPolygonEnd(idx));
+        Ok(())
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events
+            .push(GeomEvent::GeometryCollectionBegin(size, idx));
+        Ok(())
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::GeometryCollectionEnd(idx));
+        Ok(())
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::CircularStringBegin(size, idx));
+        Ok(())
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::CircularStringEnd(idx));
+        Ok(())
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::CompoundCurveBegin(size, idx));
+        Ok(())
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::CompoundCurveEnd(idx));
+        Ok(())
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::CurvePolygonBegin(size, idx));
+        Ok(())
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::CurvePolygonEnd(idx));
+        Ok(())
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::MultiCurveBegin(size, idx));
+        Ok(())
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::MultiCurveEnd(idx));
+        Ok(())
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::MultiSurfaceBegin(size, idx));
+        Ok(())
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::MultiSurfaceEnd(idx));
+        Ok(())
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.events
+            .push(GeomEvent::TriangleBegin(tagged, size, idx));
+        Ok(())
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::TriangleEnd(tagged, idx));
+        Ok(())
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events
+            .push(GeomEvent::PolyhedralSurfaceBegin(size, idx));
+        Ok(())
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::PolyhedralSurfaceEnd(idx));
+        Ok(())
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::TinBegin(size, idx));
+        Ok(())
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.events.push(GeomEvent::TinEnd(idx));
+        Ok(())
+    }
+}
+
+/// Owned feature with recorded geometry and typed properties
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecordedFeature {
+    /// Positional index of the feature in its dataset
+    pub idx: u64,
+    /// Properties in source order
+    pub properties: Vec<(String, OwnedColumnValue)>,
+    /// Feature geometry, `None` if the feature has no geometry
+    pub geometry: Option<RecordedGeometry>,
+}
+
+impl RecordedFeature {
+    /// Value of property `name`
+    pub fn property_value(&self, name: &str) -> Option<&OwnedColumnValue> {
+        self.properties
+            .iter()
+            .find(|(colname, _)| colname == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Replay feature events, including properties and geometry, into `processor`.
+    pub fn process<P: FeatureProcessor>(&self, processor: &mut P) -> Result<()> {
+        processor.feature_begin(self.idx)?;
+        processor.properties_begin()?;
+        self.process_properties(processor)?;
+        processor.properties_end()?;
+        if let Some(geometry) = &self.geometry {
+            processor.geometry_begin()?;
+            geometry.process_geom(processor)?;
+            processor.geometry_end()?;
+        }
+        processor.feature_end(self.idx)
+    }
+}
+
+impl FeatureProperties for RecordedFeature {
+    fn process_properties<P: PropertyProcessor>(&self, processor: &mut P) -> Result<bool> {
+        for (i, (name, value)) in self.properties.iter().enumerate() {
+            if processor.property(i, name, &value.as_column_value())? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Replays the geometry of the feature, if present.
+impl GeozeroGeometry for RecordedFeature {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        match &self.geometry {
+            Some(geometry) => geometry.process_geom(processor),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Processor recording all features of a dataset
+///
+/// Geometry events outside of a feature are recorded into a feature of its own.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::geojson::GeoJsonLineReader;
+/// use geozero::{FeatureRecorder, GeozeroDatasource};
+///
+/// let input = r#"{"type": "Feature", "properties": {"name": "a"}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+/// {"type": "Feature", "properties": {"name": "b"}, "geometry": {"type": "Point", "coordinates": [2, 2]}}"#;
+///
+/// let mut recorder = FeatureRecorder::new();
+/// GeoJsonLineReader::new(input.as_bytes()).process(&mut recorder).unwrap();
+/// let features = recorder.into_features();
+/// assert_eq!(features.len(), 2);
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FeatureRecorder {
+    features: Vec<RecordedFeature>,
+}
+
+impl FeatureRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recorded features
+    pub fn features(&self) -> &[RecordedFeature] {
+        &self.features
+    }
+
+    /// Take recorded features
+    pub fn into_features(self) -> Vec<RecordedFeature> {
+        self.features
+    }
+
+    fn feature(&mut self) -> &mut RecordedFeature {
+        if self.features.is_empty() {
+            self.features.push(RecordedFeature::default());
+        }
+        let last = self.features.len() - 1;
+        &mut self.features[last]
+    }
+
+    fn geometry(&mut self) -> &mut RecordedGeometry {
+        self.feature().geometry.get_or_insert_with(Default::default)
+    }
+}
+
+impl FeatureProcessor for FeatureRecorder {
+    fn dataset_begin(&mut self, _name: Option<&str>) -> Result<()> {
+        self.features.clear();
+        Ok(())
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        self.features.push(RecordedFeature {
+            idx,
+            ..Default::default()
+        });
+        Ok(())
+    }
+    fn geometry_begin(&mut self) -> Result<()> {
+        self.geometry();
+        Ok(())
+    }
+}
+
+impl PropertyProcessor for FeatureRecorder {
+    fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.feature()
+            .properties
+            .push((name.to_string(), value.into()));
+        Ok(false)
+    }
+}
+
+impl GeomProcessor for FeatureRecorder {
+    fn dimensions(&self) -> CoordDimensions {
+        RecordedGeometry::default().dimensions()
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.geometry().srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.geometry().xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.geometry().coordinate(x, y, z, m, t, tm, idx)
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        self.geometry().empty_point(idx)
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.geometry().point_begin(idx)
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().point_end(idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().multipoint_begin(size, idx)
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().multipoint_end(idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.geometry().linestring_begin(tagged, size, idx)
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.geometry().linestring_end(tagged, idx)
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().multilinestring_begin(size, idx)
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().multilinestring_end(idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.geometry().polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.geometry().polygon_end(tagged, idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().multipolygon_begin(size, idx)
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().multipolygon_end(idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().geometrycollection_begin(size, idx)
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().geometrycollection_end(idx)
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().circularstring_begin(size, idx)
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().circularstring_end(idx)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().compoundcurve_begin(size, idx)
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().compoundcurve_end(idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().curvepolygon_begin(size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().curvepolygon_end(idx)
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().multicurve_begin(size, idx)
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().multicurve_end(idx)
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().multisurface_begin(size, idx)
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().multisurface_end(idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.geometry().triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.geometry().triangle_end(tagged, idx)
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().polyhedralsurface_begin(size, idx)
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().polyhedralsurface_end(idx)
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.geometry().tin_begin(size, idx)
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.geometry().tin_end(idx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn compact_coordinates() {
+        let mut geom = RecordedGeometry::new();
+        geom.linestring_begin(true, 3, 0).unwrap();
+        for idx in 0..3 {
+            geom.xy(idx as f64, 1.0, idx).unwrap();
+        }
+        geom.linestring_end(true, 0).unwrap();
+        assert_eq!(geom.num_coords(), 3);
+        assert_eq!(geom.events.len(), 3);
+        assert_eq!(geom.ordinates, [0.0, 1.0, 1.0, 1.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn replay_dimensions() {
+        let mut geom = RecordedGeometry::new();
+        GeomProcessor::srid(&mut geom, Some(4326)).unwrap();
+        geom.multipoint_begin(4, 0).unwrap();
+        geom.coordinate(1.0, 2.0, Some(3.0), Some(4.0), Some(5.0), Some(6), 0)
+            .unwrap();
+        geom.coordinate(2.0, 3.0, Some(4.0), Some(5.0), Some(6.0), Some(7), 1)
+            .unwrap();
+        geom.coordinate(3.0, 4.0, None, Some(5.0), None, None, 2)
+            .unwrap();
+        geom.xy(4.0, 5.0, 3).unwrap();
+        geom.multipoint_end(0).unwrap();
+        assert_eq!(geom.num_coords(), 4);
+        assert_eq!(GeozeroGeometry::srid(&geom), Some(4326));
+
+        let mut replayed = RecordedGeometry::new();
+        geom.process_geom(&mut replayed).unwrap();
+        assert_eq!(replayed, geom);
+
+        // XY only target
+        let mut bounds = crate::BoundsProcessor::new();
+        geom.process_geom(&mut bounds).unwrap();
+        assert_eq!(bounds.bounds().unwrap().z, Some((3.0, 4.0)));
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkb")]
+    fn replay_curves_and_surfaces() {
+        use crate::wkb::Ewkb;
+        use crate::ToWkt;
+
+        let geometries = [
+            // CURVEPOLYGON(COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,3 0,3 -1,0 -1,0 0)))
+            ("010A0000000100000001090000000200000001080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F0000000000000040000000000000000001020000000500000000000000000000400000000000000000000000000000084000000000000000000000000000000840000000000000F0BF0000000000000000000000000000F0BF00000000000000000000000000000000",
+             "CURVEPOLYGON(COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,3 0,3 -1,0 -1,0 0)))"),
+            // MULTICURVE((0 0, 5 5),CIRCULARSTRING(4 0, 4 4, 8 4))
+            ("010B000000020000000102000000020000000000000000000000000000000000000000000000000014400000000000001440010800000003000000000000000000104000000000000000000000000000001040000000000000104000000000000020400000000000001040",
+             "MULTICURVE((0 0,5 5),CIRCULARSTRING(4 0,4 4,8 4))"),
+            // TIN(((0 0 0,0 0 1,0 1 0,0 0 0)),((0 0 0,0 1 0,1 1 0,0 0 0)))
+            ("0110000080020000000111000080010000000400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000F03F0000000000000000000000000000F03F0000000000000000000000000000000000000000000000000000000000000000011100008001000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000F03F0000000000000000000000000000F03F000000000000F03F0000000000000000000000000000000000000000000000000000000000000000",
+             "TIN(((0 0 0,0 0 1,0 1 0,0 0 0)),((0 0 0,0 1 0,1 1 0,0 0 0)))"),
+        ];
+        for (ewkb, wkt) in geometries {
+            let mut geom = RecordedGeometry::new();
+            Ewkb(hex::decode(ewkb).unwrap())
+                .process_geom(&mut geom)
+                .unwrap();
+            assert_eq!(geom.to_wkt_ndim(CoordDimensions::xyz()).unwrap(), wkt);
+        }
+    }
+
+    #[test]
+    fn properties_typed_order() {
+        let feature = RecordedFeature {
+            properties: vec![
+                (
+                    "name".to_string(),
+                    OwnedColumnValue::String("a".to_string()),
+                ),
+                ("area".to_string(), OwnedColumnValue::Double(1.5)),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(feature.properties_typed().unwrap(), feature.properties);
+    }
+
+    #[test]
+    fn feature_recorder() {
+        let mut recorder = FeatureRecorder::new();
+        recorder.dataset_begin(None).unwrap();
+        for idx in 0..2 {
+            recorder.feature_begin(idx).unwrap();
+            recorder.properties_begin().unwrap();
+            recorder
+                .property(0, "name", &ColumnValue::String("a"))
+                .unwrap();
+            recorder.properties_end().unwrap();
+            recorder.geometry_begin().unwrap();
+            recorder.point_begin(0).unwrap();
+            recorder.xy(1.0, idx as f64, 0).unwrap();
+            recorder.point_end(0).unwrap();
+            recorder.geometry_end().unwrap();
+            recorder.feature_end(idx).unwrap();
+        }
+        recorder.dataset_end().unwrap();
+
+        let features = recorder.into_features();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[1].idx, 1);
+        assert_eq!(
+            features[1].property_value("name"),
+            Some(&OwnedColumnValue::String("a".to_string()))
+        );
+        assert_eq!(features[1].geometry.as_ref().unwrap().num_coords(), 1);
+
+        let mut replayed = FeatureRecorder::new();
+        for feature in &features {
+            feature.process(&mut replayed).unwrap();
+        }
+        assert_eq!(replayed.features(), features);
+    }
+}
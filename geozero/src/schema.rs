@@ -0,0 +1,111 @@
+use crate::geometry_processor::CoordDimensions;
+use crate::property_processor::ColumnValue;
+
+/// Dataset schema, as declared by a reader via [`FeatureProcessor::schema`](crate::FeatureProcessor::schema)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Schema {
+    /// Property columns in dataset order
+    pub columns: Vec<Column>,
+    /// Geometry type of all features, [`GeometryType::Unknown`] for mixed or undeclared types
+    pub geometry_type: GeometryType,
+    /// Coordinate dimensions stored in the dataset
+    pub dims: CoordDimensions,
+}
+
+impl Schema {
+    pub fn new(columns: Vec<Column>) -> Self {
+        Schema {
+            columns,
+            ..Default::default()
+        }
+    }
+
+    /// Position of column `name`
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|col| col.name == name)
+    }
+}
+
+/// Property column declaration
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Column {
+    pub name: String,
+    /// Value type, `None` if the format doesn't declare types
+    pub column_type: Option<ColumnType>,
+}
+
+impl Column {
+    pub fn new(name: &str, column_type: Option<ColumnType>) -> Self {
+        Column {
+            name: name.to_string(),
+            column_type,
+        }
+    }
+}
+
+/// Property value type, corresponding to [`ColumnValue`] variants
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnType {
+    Byte,
+    UByte,
+    Bool,
+    Short,
+    UShort,
+    Int,
+    UInt,
+    Long,
+    ULong,
+    Float,
+    Double,
+    String,
+    Json,
+    DateTime,
+    Binary,
+}
+
+impl ColumnType {
+    /// Type of a property value, `None` for [`ColumnValue::Null`]
+    pub fn of(value: &ColumnValue) -> Option<Self> {
+        let column_type = match value {
+            ColumnValue::Byte(_) => ColumnType::Byte,
+            ColumnValue::UByte(_) => ColumnType::UByte,
+            ColumnValue::Bool(_) => ColumnType::Bool,
+            ColumnValue::Short(_) => ColumnType::Short,
+            ColumnValue::UShort(_) => ColumnType::UShort,
+            ColumnValue::Int(_) => ColumnType::Int,
+            ColumnValue::UInt(_) => ColumnType::UInt,
+            ColumnValue::Long(_) => ColumnType::Long,
+            ColumnValue::ULong(_) => ColumnType::ULong,
+            ColumnValue::Float(_) => ColumnType::Float,
+            ColumnValue::Double(_) => ColumnType::Double,
+            ColumnValue::String(_) => ColumnType::String,
+            ColumnValue::Json(_) => ColumnType::Json,
+            ColumnValue::DateTime(_) => ColumnType::DateTime,
+            ColumnValue::Binary(_) => ColumnType::Binary,
+            ColumnValue::Null => return None,
+        };
+        Some(column_type)
+    }
+}
+
+/// Geometry type of a dataset
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GeometryType {
+    #[default]
+    Unknown,
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    CircularString,
+    CompoundCurve,
+    CurvePolygon,
+    MultiCurve,
+    MultiSurface,
+    Triangle,
+    PolyhedralSurface,
+    Tin,
+}
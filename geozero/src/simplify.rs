@@ -0,0 +1,473 @@
+use crate::coord::Coord;
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::PropertyProcessor;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// Line simplification algorithm of a [`SimplifyProcessor`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimplifyMethod {
+    /// Ramer-Douglas-Peucker, removing points within a distance tolerance
+    DouglasPeucker,
+    /// Visvalingam-Whyatt, removing points with an effective triangle area within the tolerance
+    VisvalingamWhyatt,
+}
+
+fn segment_distance(p: &Coord, a: &Coord, b: &Coord) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len2 = dx * dx + dy * dy;
+    if len2 == 0.0 {
+        return (p.x - a.x).hypot(p.y - a.y);
+    }
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / len2).clamp(0.0, 1.0);
+    (p.x - (a.x + t * dx)).hypot(p.y - (a.y + t * dy))
+}
+
+fn triangle_area(a: &Coord, b: &Coord, c: &Coord) -> f64 {
+    ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0
+}
+
+/// Tolerance up to which each point is removed. Endpoints are never removed.
+fn douglas_peucker_importance(coords: &[Coord]) -> Vec<f64> {
+    let mut importance = vec![f64::INFINITY; coords.len()];
+    if coords.len() < 3 {
+        return importance;
+    }
+    let mut segments = vec![(0, coords.len() - 1, f64::INFINITY)];
+    while let Some((first, last, parent)) = segments.pop() {
+        if last <= first + 1 {
+            continue;
+        }
+        let (mut split, mut max_dist) = (first + 1, -1.0);
+        for i in first + 1..last {
+            let dist = segment_distance(&coords[i], &coords[first], &coords[last]);
+            if dist > max_dist {
+                (split, max_dist) = (i, dist);
+            }
+        }
+        // A point is only kept if the point splitting its parent segment is kept
+        let split_importance = max_dist.min(parent);
+        importance[split] = split_importance;
+        segments.push((first, split, split_importance));
+        segments.push((split, last, split_importance));
+    }
+    importance
+}
+
+struct Area(f64, usize);
+
+impl PartialEq for Area {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Area {}
+
+impl PartialOrd for Area {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Area {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0).then(self.1.cmp(&other.1))
+    }
+}
+
+/// Effective area up to which each point is removed. Endpoints are never removed.
+fn visvalingam_importance(coords: &[Coord]) -> Vec<f64> {
+    let n = coords.len();
+    let mut importance = vec![f64::INFINITY; n];
+    if n < 3 {
+        return importance;
+    }
+    let mut prev: Vec<usize> = (0..n).map(|i| i.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| i + 1).collect();
+    let mut areas = vec![f64::INFINITY; n];
+    let mut heap = BinaryHeap::new();
+    for i in 1..n - 1 {
+        areas[i] = triangle_area(&coords[i - 1], &coords[i], &coords[i + 1]);
+        heap.push(Reverse(Area(areas[i], i)));
+    }
+    let mut max_area: f64 = 0.0;
+    while let Some(Reverse(Area(area, i))) = heap.pop() {
+        if importance[i].is_finite() || area != areas[i] {
+            // Already removed or outdated
+            continue;
+        }
+        // Effective area is never smaller than the area of previously removed points
+        max_area = max_area.max(area);
+        importance[i] = max_area;
+        let (p, nx) = (prev[i], next[i]);
+        next[p] = nx;
+        prev[nx] = p;
+        for j in [p, nx] {
+            if j != 0 && j != n - 1 {
+                areas[j] = triangle_area(&coords[prev[j]], &coords[j], &coords[next[j]]);
+                heap.push(Reverse(Area(areas[j], j)));
+            }
+        }
+    }
+    importance
+}
+
+/// Points to keep, at least `min_points`
+fn keep_mask(importance: &[f64], tolerance: f64, min_points: usize) -> Vec<bool> {
+    let mut keep: Vec<bool> = importance.iter().map(|imp| *imp > tolerance).collect();
+    let count = keep.iter().filter(|k| **k).count();
+    if count < min_points {
+        let mut removed: Vec<usize> = (0..keep.len()).filter(|i| !keep[*i]).collect();
+        removed.sort_by(|a, b| importance[*b].total_cmp(&importance[*a]));
+        for i in removed.into_iter().take(min_points - count) {
+            keep[i] = true;
+        }
+    }
+    keep
+}
+
+/// Processor simplifying linestrings and polygon rings
+///
+/// Coordinates of each linestring are buffered, simplified and passed on with the reduced size.
+/// Polygon rings stay closed and keep at least 4 points. Other geometries are passed unchanged.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::{GeozeroGeometry, SimplifyMethod, SimplifyProcessor};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor =
+///     SimplifyProcessor::new(WktWriter::new(&mut out), SimplifyMethod::DouglasPeucker, 1.0);
+/// Wkt("LINESTRING(0 0,5 4,11 5.5,17.3 3.2,27.8 0.1)").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "LINESTRING(0 0,5 4,11 5.5,27.8 0.1)");
+/// # }
+/// ```
+pub struct SimplifyProcessor<P> {
+    inner: P,
+    method: SimplifyMethod,
+    tolerance: f64,
+    /// Open geometries by nesting level, `true` for geometries whose direct children are rings
+    containers: Vec<bool>,
+    /// Coordinates of the current linestring
+    line: Option<Vec<Coord>>,
+}
+
+impl<P: GeomProcessor> SimplifyProcessor<P> {
+    pub fn new(inner: P, method: SimplifyMethod, tolerance: f64) -> Self {
+        SimplifyProcessor {
+            inner,
+            method,
+            tolerance,
+            containers: Vec::new(),
+            line: None,
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn begin(&mut self, rings: bool) {
+        self.containers.push(rings);
+    }
+
+    fn end(&mut self) {
+        self.containers.pop();
+    }
+
+    fn simplify(&self, coords: &[Coord], ring: bool) -> Vec<bool> {
+        let importance = match self.method {
+            SimplifyMethod::DouglasPeucker => douglas_peucker_importance(coords),
+            SimplifyMethod::VisvalingamWhyatt => visvalingam_importance(coords),
+        };
+        let min_points = if ring { 4 } else { 2 };
+        keep_mask(&importance, self.tolerance, min_points)
+    }
+
+    fn coord(&mut self, coord: Coord, idx: usize) -> Result<()> {
+        match &mut self.line {
+            Some(line) => {
+                line.push(coord);
+                Ok(())
+            }
+            None => self.emit(&coord, idx),
+        }
+    }
+
+    fn emit(&mut self, c: &Coord, idx: usize) -> Result<()> {
+        if self.inner.multi_dim() {
+            self.inner.coordinate(c.x, c.y, c.z, c.m, c.t, c.tm, idx)
+        } else {
+            self.inner.xy(c.x, c.y, idx)
+        }
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for SimplifyProcessor<P> {
+    forward!(
+        inner => dataset_begin, schema, dataset_end, feature_begin, feature_end, properties_begin,
+        properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for SimplifyProcessor<P> {
+    forward!(inner => property);
+}
+
+impl<P: GeomProcessor> GeomProcessor for SimplifyProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let coord = Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+            t: None,
+            tm: None,
+        };
+        self.coord(coord, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let coord = Coord { x, y, z, m, t, tm };
+        self.coord(coord, idx)
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.line = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        let coords = self.line.take().unwrap_or_default();
+        // Ring context comes from the parent geometry, also for rings passed as tagged linestrings
+        let ring = self.containers.last() == Some(&true);
+        let keep = self.simplify(&coords, ring);
+        let size = keep.iter().filter(|k| **k).count();
+        self.inner.linestring_begin(tagged, size, idx)?;
+        let kept = coords.iter().zip(keep).filter_map(|(c, k)| k.then_some(c));
+        for (i, coord) in kept.enumerate() {
+            self.emit(coord, i)?;
+        }
+        self.inner.linestring_end(tagged, idx)
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(false);
+        self.inner.multilinestring_begin(size, idx)
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.multilinestring_end(idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin(true);
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.polygon_end(tagged, idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(false);
+        self.inner.multipolygon_begin(size, idx)
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.multipolygon_end(idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(false);
+        self.inner.geometrycollection_begin(size, idx)
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.geometrycollection_end(idx)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(false);
+        self.inner.compoundcurve_begin(size, idx)
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.compoundcurve_end(idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(true);
+        self.inner.curvepolygon_begin(size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.curvepolygon_end(idx)
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(false);
+        self.inner.multicurve_begin(size, idx)
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.multicurve_end(idx)
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(false);
+        self.inner.multisurface_begin(size, idx)
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.multisurface_end(idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin(true);
+        self.inner.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.triangle_end(tagged, idx)
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(false);
+        self.inner.polyhedralsurface_begin(size, idx)
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.polyhedralsurface_end(idx)
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(false);
+        self.inner.tin_begin(size, idx)
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.end();
+        self.inner.tin_end(idx)
+    }
+    forward!(
+        inner => dimensions, multi_dim, supports_curves, srid, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, circularstring_begin, circularstring_end,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "with-wkt")]
+    fn simplify(wkt: &str, method: SimplifyMethod, tolerance: f64) -> String {
+        use crate::wkt::{Wkt, WktWriter};
+        use crate::GeozeroGeometry;
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = SimplifyProcessor::new(WktWriter::new(&mut out), method, tolerance);
+        Wkt(wkt).process_geom(&mut processor).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn douglas_peucker() {
+        let dp = SimplifyMethod::DouglasPeucker;
+        let line = "LINESTRING(0 0,5 4,11 5.5,17.3 3.2,27.8 0.1)";
+        assert_eq!(
+            simplify(line, dp, 1.0),
+            "LINESTRING(0 0,5 4,11 5.5,27.8 0.1)"
+        );
+        assert_eq!(simplify(line, dp, 10.0), "LINESTRING(0 0,27.8 0.1)");
+        assert_eq!(simplify(line, dp, 0.0), line);
+        assert_eq!(
+            simplify("POLYGON((0 0,10 0,10 0.1,10 10,0 10,0 0))", dp, 1.0),
+            "POLYGON((0 0,10 0,10 10,0 10,0 0))"
+        );
+        // Rings keep at least 4 points
+        assert_eq!(
+            simplify("POLYGON((0 0,1 0,1 1,0 1,0 0))", dp, 10.0),
+            "POLYGON((0 0,1 0,1 1,0 0))"
+        );
+        assert_eq!(
+            simplify("MULTIPOINT(0 0,0.1 0,0.2 0)", dp, 10.0),
+            "MULTIPOINT(0 0,0.1 0,0.2 0)"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn curvepolygon_rings() {
+        use crate::wkt::WktWriter;
+
+        // Linear ring of a curve polygon passed as tagged linestring
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = SimplifyProcessor::new(
+            WktWriter::new(&mut out),
+            SimplifyMethod::DouglasPeucker,
+            10.0,
+        );
+        processor.curvepolygon_begin(1, 0).unwrap();
+        processor.linestring_begin(true, 5, 0).unwrap();
+        for (i, (x, y)) in [(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]
+            .into_iter()
+            .enumerate()
+        {
+            processor.xy(x, y, i).unwrap();
+        }
+        processor.linestring_end(true, 0).unwrap();
+        processor.curvepolygon_end(0).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "CURVEPOLYGON(LINESTRING(0 0,1 0,1 1,0 0))"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn visvalingam_whyatt() {
+        let vw = SimplifyMethod::VisvalingamWhyatt;
+        assert_eq!(
+            simplify("LINESTRING(5 2,3 8,6 20,7 25,10 10)", vw, 30.0),
+            "LINESTRING(5 2,7 25,10 10)"
+        );
+        assert_eq!(
+            simplify("POLYGON((0 0,10 0,10 0.1,10 10,0 10,0 0))", vw, 1.0),
+            "POLYGON((0 0,10 0,10 10,0 10,0 0))"
+        );
+        assert_eq!(
+            simplify("POLYGON((0 0,1 0,1 1,0 1,0 0))", vw, 10.0),
+            "POLYGON((0 0,1 0,1 1,0 0))"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-geojson")]
+    fn sizes_and_dimensions() {
+        use crate::geojson::GeoJson;
+        use crate::{
+            CoordDimensions, GeozeroGeometry, RecordedGeometry, ToWkt, ValidatingProcessor,
+        };
+
+        let geojson = GeoJson(
+            r#"{"type": "MultiLineString", "coordinates": [[[0,0,1],[1,0.01,2],[2,0,3]],[[0,0,1],[1,1,2]]]}"#,
+        );
+        let mut processor = SimplifyProcessor::new(
+            ValidatingProcessor::new(RecordedGeometry::new()),
+            SimplifyMethod::DouglasPeucker,
+            0.1,
+        );
+        geojson.process_geom(&mut processor).unwrap();
+        let mut validator = processor.into_inner();
+        validator.finish().unwrap();
+        let geom = validator.into_inner();
+        assert_eq!(
+            geom.to_wkt_ndim(CoordDimensions::xyz()).unwrap(),
+            "MULTILINESTRING((0 0 1,2 0 3),(0 0 1,1 1 2))"
+        );
+    }
+}
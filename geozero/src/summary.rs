@@ -0,0 +1,499 @@
+use crate::bbox::{expand_bounds, Bounds};
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::geometry_processor::{CoordDimensions, GeomProcessor};
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use crate::schema::{ColumnType, GeometryType, Schema};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Inferred schema and value statistics of a property
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertySummary {
+    pub name: String,
+    /// Type of all values, or the declared type for columns without values.
+    /// Mixed numeric types are reported as `Double`, other mixed types as `String`.
+    pub column_type: Option<ColumnType>,
+    /// Number of non-null values
+    pub count: u64,
+    /// Number of features with a null or missing value
+    pub null_count: u64,
+    /// First distinct values
+    pub samples: Vec<String>,
+}
+
+impl PropertySummary {
+    fn new(name: &str, column_type: Option<ColumnType>) -> Self {
+        PropertySummary {
+            name: name.to_string(),
+            column_type,
+            count: 0,
+            null_count: 0,
+            samples: Vec::new(),
+        }
+    }
+}
+
+fn is_numeric(column_type: ColumnType) -> bool {
+    !matches!(
+        column_type,
+        ColumnType::Bool
+            | ColumnType::String
+            | ColumnType::Json
+            | ColumnType::DateTime
+            | ColumnType::Binary
+    )
+}
+
+fn merge_types(a: ColumnType, b: ColumnType) -> ColumnType {
+    if a == b {
+        a
+    } else if is_numeric(a) && is_numeric(b) {
+        ColumnType::Double
+    } else {
+        ColumnType::String
+    }
+}
+
+/// Processor summarizing a dataset, similar to `ogrinfo`
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::geojson::GeoJsonLineReader;
+/// use geozero::{DatasetSummary, GeozeroDatasource};
+///
+/// let input = r#"{"type": "Feature", "properties": {"name": "a"}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+/// {"type": "Feature", "properties": {"name": "b", "pop": 5}, "geometry": {"type": "LineString", "coordinates": [[1, 1], [2, 2]]}}"#;
+///
+/// let mut summary = DatasetSummary::new();
+/// GeoJsonLineReader::new(input.as_bytes()).process(&mut summary).unwrap();
+/// assert_eq!(summary.feature_count, 2);
+/// assert_eq!(summary.vertex_count, 3);
+/// assert_eq!(summary.properties[1].null_count, 1);
+/// println!("{summary}");
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DatasetSummary {
+    pub name: Option<String>,
+    pub feature_count: u64,
+    /// Number of geometries per type, in order of first appearance
+    pub geometry_types: Vec<(GeometryType, u64)>,
+    pub vertex_count: u64,
+    /// Maximal number of vertices of a single geometry
+    pub max_vertices: u64,
+    pub bounds: Option<Bounds>,
+    pub srids: BTreeSet<i32>,
+    /// Dimensions present in any coordinate
+    pub dims: CoordDimensions,
+    pub properties: Vec<PropertySummary>,
+    depth: usize,
+    geometry_vertices: u64,
+    /// Properties with a value in the current feature
+    seen: Vec<bool>,
+}
+
+impl DatasetSummary {
+    /// Maximal number of sample values per property
+    pub const MAX_SAMPLES: usize = 3;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn property_idx(&mut self, name: &str, column_type: Option<ColumnType>) -> usize {
+        if let Some(idx) = self.properties.iter().position(|prop| prop.name == name) {
+            return idx;
+        }
+        let mut prop = PropertySummary::new(name, column_type);
+        // Missing in previous features
+        prop.null_count = self.feature_count.saturating_sub(1);
+        self.properties.push(prop);
+        self.seen.push(false);
+        self.properties.len() - 1
+    }
+
+    fn begin(&mut self, geometry_type: GeometryType) {
+        if self.depth == 0 {
+            match self
+                .geometry_types
+                .iter_mut()
+                .find(|(gt, _)| *gt == geometry_type)
+            {
+                Some((_, count)) => *count += 1,
+                None => self.geometry_types.push((geometry_type, 1)),
+            }
+            self.geometry_vertices = 0;
+        }
+        self.depth += 1;
+    }
+
+    fn end(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        if self.depth == 0 {
+            self.max_vertices = self.max_vertices.max(self.geometry_vertices);
+        }
+    }
+
+    fn vertex(&mut self, x: f64, y: f64, z: Option<f64>) {
+        self.vertex_count += 1;
+        self.geometry_vertices += 1;
+        expand_bounds(&mut self.bounds, x, y, z);
+    }
+}
+
+impl fmt::Display for DatasetSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = &self.name {
+            writeln!(f, "Dataset: {name}")?;
+        }
+        writeln!(f, "Features: {}", self.feature_count)?;
+        let types = self
+            .geometry_types
+            .iter()
+            .map(|(gt, count)| format!("{gt:?} ({count})"))
+            .collect::<Vec<_>>();
+        writeln!(f, "Geometry types: {}", types.join(", "))?;
+        writeln!(
+            f,
+            "Vertices: {} (max. {} per geometry)",
+            self.vertex_count, self.max_vertices
+        )?;
+        if let Some(bounds) = &self.bounds {
+            let bbox = &bounds.bbox;
+            write!(
+                f,
+                "Extent: ({}, {}) - ({}, {})",
+                bbox.minx, bbox.miny, bbox.maxx, bbox.maxy
+            )?;
+            if let Some((minz, maxz)) = bounds.z {
+                write!(f, ", Z: {minz} - {maxz}")?;
+            }
+            writeln!(f)?;
+        }
+        if !self.srids.is_empty() {
+            let srids = self.srids.iter().map(|srid| srid.to_string());
+            writeln!(f, "SRID: {}", srids.collect::<Vec<_>>().join(", "))?;
+        }
+        let dims = [
+            ("Z", self.dims.z),
+            ("M", self.dims.m),
+            ("T", self.dims.t),
+            ("TM", self.dims.tm),
+        ];
+        let dims: String = dims.iter().filter(|(_, d)| *d).map(|(n, _)| *n).collect();
+        writeln!(f, "Dimensions: XY{dims}")?;
+        if !self.properties.is_empty() {
+            writeln!(f, "Properties:")?;
+        }
+        for prop in &self.properties {
+            let column_type = prop
+                .column_type
+                .map(|t| format!("{t:?}"))
+                .unwrap_or_else(|| "Unknown".to_string());
+            writeln!(
+                f,
+                "  {}: {column_type} ({} values, {} nulls) [{}]",
+                prop.name,
+                prop.count,
+                prop.null_count,
+                prop.samples.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl FeatureProcessor for DatasetSummary {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        *self = DatasetSummary {
+            name: name.map(str::to_string),
+            ..Default::default()
+        };
+        Ok(())
+    }
+    fn schema(&mut self, schema: &Schema) -> Result<()> {
+        for column in &schema.columns {
+            self.property_idx(&column.name, column.column_type);
+        }
+        Ok(())
+    }
+    fn feature_begin(&mut self, _idx: u64) -> Result<()> {
+        self.feature_count += 1;
+        self.seen.iter_mut().for_each(|seen| *seen = false);
+        Ok(())
+    }
+    fn feature_end(&mut self, _idx: u64) -> Result<()> {
+        for (prop, seen) in self.properties.iter_mut().zip(&self.seen) {
+            if !seen {
+                prop.null_count += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PropertyProcessor for DatasetSummary {
+    fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        let Some(column_type) = ColumnType::of(value) else {
+            return Ok(false);
+        };
+        let idx = self.property_idx(name, Some(column_type));
+        self.seen[idx] = true;
+        let prop = &mut self.properties[idx];
+        prop.column_type = Some(match (prop.count, prop.column_type) {
+            (0, _) | (_, None) => column_type,
+            (_, Some(prev)) => merge_types(prev, column_type),
+        });
+        prop.count += 1;
+        let sample = value.to_string();
+        if prop.samples.len() < Self::MAX_SAMPLES && !prop.samples.contains(&sample) {
+            prop.samples.push(sample);
+        }
+        Ok(false)
+    }
+}
+
+impl GeomProcessor for DatasetSummary {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions {
+            z: true,
+            m: true,
+            t: true,
+            tm: true,
+        }
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        if let Some(srid) = srid {
+            self.srids.insert(srid);
+        }
+        Ok(())
+    }
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.vertex(x, y, None);
+        Ok(())
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        self.dims.z |= z.is_some();
+        self.dims.m |= m.is_some();
+        self.dims.t |= t.is_some();
+        self.dims.tm |= tm.is_some();
+        self.vertex(x, y, z);
+        Ok(())
+    }
+    fn empty_point(&mut self, _idx: usize) -> Result<()> {
+        if self.depth == 0 {
+            self.begin(GeometryType::Point);
+            self.end();
+        }
+        Ok(())
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::Point);
+        Ok(())
+    }
+    fn point_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn multipoint_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiPoint);
+        Ok(())
+    }
+    fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn linestring_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::LineString);
+        Ok(())
+    }
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiLineString);
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn polygon_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::Polygon);
+        Ok(())
+    }
+    fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiPolygon);
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn geometrycollection_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::GeometryCollection);
+        Ok(())
+    }
+    fn geometrycollection_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn circularstring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::CircularString);
+        Ok(())
+    }
+    fn circularstring_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn compoundcurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::CompoundCurve);
+        Ok(())
+    }
+    fn compoundcurve_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn curvepolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::CurvePolygon);
+        Ok(())
+    }
+    fn curvepolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn multicurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiCurve);
+        Ok(())
+    }
+    fn multicurve_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn multisurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiSurface);
+        Ok(())
+    }
+    fn multisurface_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn triangle_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::Triangle);
+        Ok(())
+    }
+    fn triangle_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn polyhedralsurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::PolyhedralSurface);
+        Ok(())
+    }
+    fn polyhedralsurface_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+    fn tin_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.begin(GeometryType::Tin);
+        Ok(())
+    }
+    fn tin_end(&mut self, _idx: usize) -> Result<()> {
+        self.end();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "with-geojson")]
+    fn summary() {
+        use crate::geojson::GeoJsonLineReader;
+        use crate::{Bbox, GeozeroDatasource};
+
+        let input = r#"{"type": "Feature", "properties": {"name": "a", "pop": 5}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+{"type": "Feature", "properties": {"name": "b", "pop": 2.5}, "geometry": {"type": "MultiPoint", "coordinates": [[1, 1, 3], [2, 5, 4]]}}
+{"type": "Feature", "properties": {"name": "a", "pop": null, "flag": true}, "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [4, 0], [4, 4], [0, 0]]]}}
+{"type": "Feature", "properties": {"name": "c"}, "geometry": {"type": "Point", "coordinates": [2, 2]}}"#;
+        let mut summary = DatasetSummary::new();
+        GeoJsonLineReader::new(input.as_bytes())
+            .process(&mut summary)
+            .unwrap();
+
+        assert_eq!(summary.feature_count, 4);
+        assert_eq!(
+            summary.geometry_types,
+            [
+                (GeometryType::Point, 2),
+                (GeometryType::MultiPoint, 1),
+                (GeometryType::Polygon, 1)
+            ]
+        );
+        assert_eq!(summary.vertex_count, 8);
+        assert_eq!(summary.max_vertices, 4);
+        let bounds = summary.bounds.unwrap();
+        assert_eq!(bounds.bbox, Bbox::new(0.0, 0.0, 4.0, 5.0));
+        assert_eq!(bounds.z, Some((3.0, 4.0)));
+        assert_eq!(summary.dims, CoordDimensions::xyz());
+        assert_eq!(
+            summary.properties,
+            [
+                PropertySummary {
+                    name: "name".to_string(),
+                    column_type: Some(ColumnType::String),
+                    count: 4,
+                    null_count: 0,
+                    samples: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                },
+                PropertySummary {
+                    name: "pop".to_string(),
+                    column_type: Some(ColumnType::Double),
+                    count: 2,
+                    null_count: 2,
+                    samples: vec!["5".to_string(), "2.5".to_string()],
+                },
+                PropertySummary {
+                    name: "flag".to_string(),
+                    column_type: Some(ColumnType::Bool),
+                    count: 1,
+                    null_count: 3,
+                    samples: vec!["true".to_string()],
+                },
+            ]
+        );
+        assert_eq!(
+            summary.to_string(),
+            "Features: 4
+Geometry types: Point (2), MultiPoint (1), Polygon (1)
+Vertices: 8 (max. 4 per geometry)
+Extent: (0, 0) - (4, 5), Z: 3 - 4
+Dimensions: XYZ
+Properties:
+  name: String (4 values, 0 nulls) [a, b, c]
+  pop: Double (2 values, 2 nulls) [5, 2.5]
+  flag: Bool (1 values, 3 nulls) [true]
+"
+        );
+    }
+}
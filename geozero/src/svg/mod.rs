@@ -34,7 +34,7 @@ pub(crate) mod conversion {
     pub trait ToSvg {
         /// Convert to SVG geometry.
         fn to_svg(&self) -> Result<String>;
-        /// Convert to SVG document.
+        /// Convert to SVG document with a view box fitted to the geometry.
         fn to_svg_document(&self) -> Result<String>;
     }
 
@@ -50,7 +50,9 @@ pub(crate) mod conversion {
         fn to_svg_document(&self) -> Result<String> {
             let mut svg_data: Vec<u8> = Vec::new();
             let mut svg = SvgWriter::new(&mut svg_data, false);
-            // svg.set_dimensions(bbox.get(0), bbox.get(1), bbox.get(2), bbox.get(3), 800, 400);
+            if let Some(bbox) = self.bbox()? {
+                svg.set_dimensions(bbox.minx, bbox.miny, bbox.maxx, bbox.maxy, 800, 400);
+            }
             svg.dataset_begin(None)?;
             svg.feature_begin(0)?;
             self.process_geom(&mut svg)?;
@@ -1,11 +1,12 @@
 use crate::error::Result;
-use crate::{FeatureProcessor, GeomProcessor, PropertyProcessor};
+use crate::{CoordPrecision, FeatureProcessor, GeomProcessor, PropertyProcessor};
 use std::io::Write;
 
 /// SVG writer.
 pub struct SvgWriter<W: Write> {
     out: W,
     invert_y: bool,
+    precision: CoordPrecision,
     view_box: Option<(f64, f64, f64, f64)>,
     size: Option<(u32, u32)>,
 }
@@ -15,10 +16,16 @@ impl<W: Write> SvgWriter<W> {
         SvgWriter {
             out,
             invert_y,
+            precision: CoordPrecision::default(),
             view_box: None,
             size: None,
         }
     }
+    /// Write coordinates with the given precision
+    pub fn with_precision(mut self, precision: CoordPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
     pub fn set_dimensions(
         &mut self,
         xmin: f64,
@@ -77,8 +84,12 @@ impl<W: Write> FeatureProcessor for SvgWriter<W> {
 }
 
 impl<W: Write> GeomProcessor for SvgWriter<W> {
+    fn supports_curves(&self) -> bool {
+        false
+    }
     fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
         let y = if self.invert_y { -y } else { y };
+        let (x, y) = (self.precision.format(x), self.precision.format(y));
         self.out.write_all(format!("{x} {y} ").as_bytes())?;
         Ok(())
     }
@@ -296,7 +307,7 @@ mod test {
         assert_eq!(
             &geom.to_svg_document().unwrap(),
             r#"<?xml version="1.0"?>
-<svg xmlns="http://www.w3.org/2000/svg" version="1.2" baseProfile="tiny" stroke-linecap="round" stroke-linejoin="round">
+<svg xmlns="http://www.w3.org/2000/svg" version="1.2" baseProfile="tiny" width="800" height="400" viewBox="123 10 177 240" stroke-linecap="round" stroke-linejoin="round">
 <g id="">
 <path d="M 220 10 300 210 170 250 123 234 220 10 Z "/>
 </g>
@@ -0,0 +1,485 @@
+use crate::error::{GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::geometry_processor::{CoordDimensions, GeomProcessor};
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use crate::schema::Schema;
+
+/// Handling of errors returned by a [`Tee`] branch
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BranchErrorPolicy {
+    /// Abort processing of all branches
+    #[default]
+    Fail,
+    /// Collect the error and stop forwarding events to the failed branch
+    Disable,
+    /// Collect the error and keep forwarding events to the branch
+    Continue,
+}
+
+struct Branch<'a> {
+    processor: Box<dyn FeatureProcessor + 'a>,
+    policy: BranchErrorPolicy,
+    enabled: bool,
+    properties_done: bool,
+    errors: Vec<GeozeroError>,
+}
+
+/// Forwards all events to a dynamic list of processors
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(all(feature = "with-geojson", feature = "with-wkt"))]
+/// # {
+/// use geozero::geojson::{GeoJson, GeoJsonWriter};
+/// use geozero::wkt::WktWriter;
+/// use geozero::{BranchErrorPolicy, GeozeroDatasource, Tee};
+///
+/// let mut json_out: Vec<u8> = Vec::new();
+/// let mut wkt_out: Vec<u8> = Vec::new();
+/// let mut tee = Tee::new(vec![
+///     Box::new(GeoJsonWriter::new(&mut json_out)),
+///     Box::new(WktWriter::new(&mut wkt_out)),
+/// ])
+/// .with_error_policy(BranchErrorPolicy::Disable);
+/// GeoJson(r#"{"type": "Point", "coordinates": [1, 2]}"#).process(&mut tee).unwrap();
+/// assert!(tee.errors(1).is_empty());
+/// drop(tee);
+/// assert_eq!(wkt_out, b"POINT(1 2)");
+/// # }
+/// ```
+pub struct Tee<'a> {
+    branches: Vec<Branch<'a>>,
+}
+
+impl<'a> Tee<'a> {
+    /// Create a `Tee` with [`BranchErrorPolicy::Fail`] for all branches.
+    pub fn new(processors: Vec<Box<dyn FeatureProcessor + 'a>>) -> Self {
+        let branches = processors
+            .into_iter()
+            .map(|processor| Branch {
+                processor,
+                policy: BranchErrorPolicy::default(),
+                enabled: true,
+                properties_done: false,
+                errors: Vec::new(),
+            })
+            .collect();
+        Tee { branches }
+    }
+
+    /// Set the error policy of all branches
+    pub fn with_error_policy(mut self, policy: BranchErrorPolicy) -> Self {
+        for branch in &mut self.branches {
+            branch.policy = policy;
+        }
+        self
+    }
+
+    /// Set the error policy of branch `idx`
+    pub fn set_error_policy(&mut self, idx: usize, policy: BranchErrorPolicy) {
+        self.branches[idx].policy = policy;
+    }
+
+    /// Number of branches
+    pub fn len(&self) -> usize {
+        self.branches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.branches.is_empty()
+    }
+
+    /// Whether branch `idx` still receives events
+    pub fn is_enabled(&self, idx: usize) -> bool {
+        self.branches[idx].enabled
+    }
+
+    /// Errors collected for branch `idx`
+    pub fn errors(&self, idx: usize) -> &[GeozeroError] {
+        &self.branches[idx].errors
+    }
+
+    /// Errors collected for all branches, in branch order
+    pub fn into_errors(self) -> Vec<Vec<GeozeroError>> {
+        self.branches
+            .into_iter()
+            .map(|branch| branch.errors)
+            .collect()
+    }
+
+    fn each<F>(&mut self, mut f: F) -> Result<()>
+    where
+        F: FnMut(&mut dyn FeatureProcessor) -> Result<()>,
+    {
+        for branch in self.branches.iter_mut().filter(|branch| branch.enabled) {
+            if let Err(e) = f(branch.processor.as_mut()) {
+                match branch.policy {
+                    BranchErrorPolicy::Fail => return Err(e),
+                    BranchErrorPolicy::Disable => {
+                        branch.enabled = false;
+                        branch.errors.push(e);
+                    }
+                    BranchErrorPolicy::Continue => branch.errors.push(e),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FeatureProcessor for Tee<'_> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.each(|p| p.dataset_begin(name))
+    }
+    fn schema(&mut self, schema: &Schema) -> Result<()> {
+        self.each(|p| p.schema(schema))
+    }
+    fn dataset_end(&mut self) -> Result<()> {
+        self.each(|p| p.dataset_end())
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        self.each(|p| p.feature_begin(idx))
+    }
+    fn feature_end(&mut self, idx: u64) -> Result<()> {
+        self.each(|p| p.feature_end(idx))
+    }
+    fn properties_begin(&mut self) -> Result<()> {
+        for branch in &mut self.branches {
+            branch.properties_done = false;
+        }
+        self.each(|p| p.properties_begin())
+    }
+    fn properties_end(&mut self) -> Result<()> {
+        self.each(|p| p.properties_end())
+    }
+    fn geometry_begin(&mut self) -> Result<()> {
+        self.each(|p| p.geometry_begin())
+    }
+    fn geometry_end(&mut self) -> Result<()> {
+        self.each(|p| p.geometry_end())
+    }
+}
+
+impl GeomProcessor for Tee<'_> {
+    fn dimensions(&self) -> CoordDimensions {
+        let mut dims = CoordDimensions::xy();
+        for branch in self.branches.iter().filter(|branch| branch.enabled) {
+            let branch_dims = branch.processor.dimensions();
+            dims.z |= branch_dims.z;
+            dims.m |= branch_dims.m;
+            dims.t |= branch_dims.t;
+            dims.tm |= branch_dims.tm;
+        }
+        dims
+    }
+    fn multi_dim(&self) -> bool {
+        self.branches
+            .iter()
+            .any(|branch| branch.enabled && branch.processor.multi_dim())
+    }
+    fn supports_curves(&self) -> bool {
+        self.branches
+            .iter()
+            .all(|branch| !branch.enabled || branch.processor.supports_curves())
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.each(|p| p.srid(srid))
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.each(|p| p.xy(x, y, idx))
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.each(|p| {
+            if p.multi_dim() {
+                p.coordinate(x, y, z, m, t, tm, idx)
+            } else {
+                p.xy(x, y, idx)
+            }
+        })
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.empty_point(idx))
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.point_begin(idx))
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.point_end(idx))
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.multipoint_begin(size, idx))
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.multipoint_end(idx))
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.linestring_begin(tagged, size, idx))
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.each(|p| p.linestring_end(tagged, idx))
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.multilinestring_begin(size, idx))
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.multilinestring_end(idx))
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.polygon_begin(tagged, size, idx))
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.each(|p| p.polygon_end(tagged, idx))
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.multipolygon_begin(size, idx))
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.multipolygon_end(idx))
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.geometrycollection_begin(size, idx))
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.geometrycollection_end(idx))
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.circularstring_begin(size, idx))
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.circularstring_end(idx))
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.compoundcurve_begin(size, idx))
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.compoundcurve_end(idx))
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.curvepolygon_begin(size, idx))
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.curvepolygon_end(idx))
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.multicurve_begin(size, idx))
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.multicurve_end(idx))
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.multisurface_begin(size, idx))
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.multisurface_end(idx))
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.triangle_begin(tagged, size, idx))
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.each(|p| p.triangle_end(tagged, idx))
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.polyhedralsurface_begin(size, idx))
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.polyhedralsurface_end(idx))
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.each(|p| p.tin_begin(size, idx))
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.each(|p| p.tin_end(idx))
+    }
+}
+
+impl PropertyProcessor for Tee<'_> {
+    /// Returns `true` (finish) when all branches have finished property processing.
+    fn property(&mut self, i: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
+        let mut all_done = true;
+        for branch in &mut self.branches {
+            if !branch.enabled || branch.properties_done {
+                continue;
+            }
+            match branch.processor.property(i, colname, colval) {
+                Ok(done) => branch.properties_done = done,
+                Err(e) => match branch.policy {
+                    BranchErrorPolicy::Fail => return Err(e),
+                    BranchErrorPolicy::Disable => {
+                        branch.enabled = false;
+                        branch.errors.push(e);
+                    }
+                    BranchErrorPolicy::Continue => branch.errors.push(e),
+                },
+            }
+            all_done &= !branch.enabled || branch.properties_done;
+        }
+        Ok(all_done)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{RecordedFeature, RecordedGeometry};
+
+    /// Fails at feature `fail_idx`
+    struct Counter<'a> {
+        features: &'a mut Vec<u64>,
+        fail_idx: Option<u64>,
+    }
+
+    impl FeatureProcessor for Counter<'_> {
+        fn feature_begin(&mut self, idx: u64) -> Result<()> {
+            if Some(idx) == self.fail_idx {
+                return Err(GeozeroError::Feature(format!("feature {idx}")));
+            }
+            Ok(())
+        }
+        fn feature_end(&mut self, idx: u64) -> Result<()> {
+            self.features.push(idx);
+            Ok(())
+        }
+    }
+    impl GeomProcessor for Counter<'_> {}
+    impl PropertyProcessor for Counter<'_> {}
+
+    fn process_features(tee: &mut Tee) -> Result<()> {
+        let feature = RecordedFeature::default();
+        tee.dataset_begin(None)?;
+        for idx in 0..3 {
+            RecordedFeature {
+                idx,
+                ..feature.clone()
+            }
+            .process(tee)?;
+        }
+        tee.dataset_end()
+    }
+
+    #[test]
+    fn error_policies() {
+        let (mut ok, mut disabled, mut continued) = (Vec::new(), Vec::new(), Vec::new());
+        let mut tee = Tee::new(vec![
+            Box::new(Counter {
+                features: &mut ok,
+                fail_idx: None,
+            }),
+            Box::new(Counter {
+                features: &mut disabled,
+                fail_idx: Some(1),
+            }),
+            Box::new(Counter {
+                features: &mut continued,
+                fail_idx: Some(1),
+            }),
+        ]);
+        tee.set_error_policy(1, BranchErrorPolicy::Disable);
+        tee.set_error_policy(2, BranchErrorPolicy::Continue);
+        process_features(&mut tee).unwrap();
+        assert!(tee.is_enabled(0));
+        assert!(!tee.is_enabled(1));
+        assert!(tee.is_enabled(2));
+        let errors = tee.into_errors();
+        assert_eq!(errors.iter().map(Vec::len).collect::<Vec<_>>(), [0, 1, 1]);
+        assert_eq!(ok, [0, 1, 2]);
+        assert_eq!(disabled, [0]);
+        // feature_end is still forwarded after the failed feature_begin
+        assert_eq!(continued, [0, 1, 2]);
+    }
+
+    #[test]
+    fn fail() {
+        let (mut first, mut second) = (Vec::new(), Vec::new());
+        let mut tee = Tee::new(vec![
+            Box::new(Counter {
+                features: &mut first,
+                fail_idx: Some(1),
+            }),
+            Box::new(Counter {
+                features: &mut second,
+                fail_idx: None,
+            }),
+        ]);
+        assert!(process_features(&mut tee).is_err());
+        drop(tee);
+        assert_eq!(first, [0]);
+        assert_eq!(second, [0]);
+    }
+
+    #[test]
+    fn dimensions() {
+        let mut recorded = RecordedGeometry::default();
+        let mut tee = Tee::new(vec![
+            Box::new(crate::ProcessorSink),
+            Box::new(RecordedFeatureSink(&mut recorded)),
+        ]);
+        assert!(tee.multi_dim());
+        tee.coordinate(1.0, 2.0, Some(3.0), None, None, None, 0)
+            .unwrap();
+        drop(tee);
+        let mut xyz = RecordedGeometry::default();
+        xyz.coordinate(1.0, 2.0, Some(3.0), None, None, None, 0)
+            .unwrap();
+        assert_eq!(recorded, xyz);
+        assert!(!Tee::new(vec![Box::new(crate::ProcessorSink)]).multi_dim());
+    }
+
+    struct RecordedFeatureSink<'a>(&'a mut RecordedGeometry);
+    impl FeatureProcessor for RecordedFeatureSink<'_> {}
+    impl PropertyProcessor for RecordedFeatureSink<'_> {}
+    impl GeomProcessor for RecordedFeatureSink<'_> {
+        fn dimensions(&self) -> CoordDimensions {
+            self.0.dimensions()
+        }
+        fn coordinate(
+            &mut self,
+            x: f64,
+            y: f64,
+            z: Option<f64>,
+            m: Option<f64>,
+            t: Option<f64>,
+            tm: Option<u64>,
+            idx: usize,
+        ) -> Result<()> {
+            self.0.coordinate(x, y, z, m, t, tm, idx)
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "with-geojson", feature = "with-csv", feature = "with-svg"))]
+    fn write_multiple_formats() {
+        use crate::csv::CsvWriter;
+        use crate::geojson::{GeoJson, GeoJsonWriter};
+        use crate::svg::SvgWriter;
+        use crate::GeozeroDatasource;
+
+        let (mut json, mut csv, mut svg) = (Vec::new(), Vec::new(), Vec::new());
+        let mut tee = Tee::new(vec![
+            Box::new(GeoJsonWriter::new(&mut json)),
+            Box::new(CsvWriter::new(&mut csv)),
+            Box::new(SvgWriter::new(&mut svg, false)),
+        ]);
+        let mut geojson = GeoJson(
+            r#"{"type": "FeatureCollection", "features": [{"type": "Feature", "properties": {"name": "a"}, "geometry": {"type": "LineString", "coordinates": [[1,2],[3,4]]}}]}"#,
+        );
+        geojson.process(&mut tee).unwrap();
+        drop(tee);
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json["features"][0]["properties"]["name"], "a");
+        assert_eq!(
+            std::str::from_utf8(&csv).unwrap(),
+            "geometry,name\n\"LINESTRING(1 2,3 4)\",a\n"
+        );
+        assert!(std::str::from_utf8(&svg).unwrap().contains("<path d="));
+    }
+}
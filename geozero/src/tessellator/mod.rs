@@ -34,6 +34,9 @@ impl<'a> Tessellator<'a> {
 }
 
 impl<'a> GeomProcessor for Tessellator<'a> {
+    fn supports_curves(&self) -> bool {
+        false
+    }
     fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
         if idx == 0 {
             self.has_started = true;
@@ -0,0 +1,290 @@
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::geometry_processor::{CoordDimensions, GeomProcessor};
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use crate::schema::Schema;
+use std::io::Write;
+
+/// Processor printing an indented tree of all events, for debugging
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::Wkt;
+/// use geozero::{GeozeroGeometry, TracingProcessor};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// Wkt("LINESTRING(1 2,3 4)").process_geom(&mut TracingProcessor::new(&mut out)).unwrap();
+/// assert_eq!(
+///     std::str::from_utf8(&out).unwrap(),
+///     "linestring_begin(tagged: true, size: 2, idx: 0)\n  xy(1, 2, idx: 0)\n  xy(3, 4, idx: 1)\nlinestring_end(tagged: true, idx: 0)\n"
+/// );
+/// # }
+/// ```
+pub struct TracingProcessor<W: Write> {
+    out: W,
+    dims: CoordDimensions,
+    depth: usize,
+}
+
+impl<W: Write> TracingProcessor<W> {
+    pub fn new(out: W) -> Self {
+        Self::with_dims(out, CoordDimensions::default())
+    }
+
+    /// Trace events with the given coordinate dimensions requested from the reader
+    pub fn with_dims(out: W, dims: CoordDimensions) -> Self {
+        TracingProcessor {
+            out,
+            dims,
+            depth: 0,
+        }
+    }
+
+    fn line(&mut self, event: &str) -> Result<()> {
+        writeln!(self.out, "{:indent$}{event}", "", indent = self.depth * 2)?;
+        Ok(())
+    }
+
+    fn begin(&mut self, event: &str) -> Result<()> {
+        self.line(event)?;
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn end(&mut self, event: &str) -> Result<()> {
+        self.depth = self.depth.saturating_sub(1);
+        self.line(event)
+    }
+
+    fn begin_geom(
+        &mut self,
+        name: &str,
+        tagged: Option<bool>,
+        size: usize,
+        idx: usize,
+    ) -> Result<()> {
+        match tagged {
+            Some(tagged) => self.begin(&format!(
+                "{name}_begin(tagged: {tagged}, size: {size}, idx: {idx})"
+            )),
+            None => self.begin(&format!("{name}_begin(size: {size}, idx: {idx})")),
+        }
+    }
+
+    fn end_geom(&mut self, name: &str, tagged: Option<bool>, idx: usize) -> Result<()> {
+        match tagged {
+            Some(tagged) => self.end(&format!("{name}_end(tagged: {tagged}, idx: {idx})")),
+            None => self.end(&format!("{name}_end(idx: {idx})")),
+        }
+    }
+}
+
+impl<W: Write> FeatureProcessor for TracingProcessor<W> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.begin(&format!("dataset_begin({name:?})"))
+    }
+    fn schema(&mut self, schema: &Schema) -> Result<()> {
+        let columns = schema
+            .columns
+            .iter()
+            .map(|col| format!("{}: {:?}", col.name, col.column_type))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.line(&format!(
+            "schema([{columns}], {:?}, {:?})",
+            schema.geometry_type, schema.dims
+        ))
+    }
+    fn dataset_end(&mut self) -> Result<()> {
+        self.end("dataset_end")
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        self.begin(&format!("feature_begin({idx})"))
+    }
+    fn feature_end(&mut self, idx: u64) -> Result<()> {
+        self.end(&format!("feature_end({idx})"))
+    }
+    fn properties_begin(&mut self) -> Result<()> {
+        self.begin("properties_begin")
+    }
+    fn properties_end(&mut self) -> Result<()> {
+        self.end("properties_end")
+    }
+    fn geometry_begin(&mut self) -> Result<()> {
+        self.begin("geometry_begin")
+    }
+    fn geometry_end(&mut self) -> Result<()> {
+        self.end("geometry_end")
+    }
+}
+
+impl<W: Write> PropertyProcessor for TracingProcessor<W> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.line(&format!("property({idx}, {name:?}, {value:?})"))?;
+        Ok(false)
+    }
+}
+
+impl<W: Write> GeomProcessor for TracingProcessor<W> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.dims
+    }
+    fn multi_dim(&self) -> bool {
+        self.dims != CoordDimensions::xy()
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.line(&format!("srid({srid:?})"))
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.line(&format!("xy({x}, {y}, idx: {idx})"))
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.line(&format!(
+            "coordinate({x}, {y}, z: {z:?}, m: {m:?}, t: {t:?}, tm: {tm:?}, idx: {idx})"
+        ))
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        self.line(&format!("empty_point(idx: {idx})"))
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.begin(&format!("point_begin(idx: {idx})"))
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.end(&format!("point_end(idx: {idx})"))
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("multipoint", None, size, idx)
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("multipoint", None, idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("linestring", Some(tagged), size, idx)
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end_geom("linestring", Some(tagged), idx)
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("multilinestring", None, size, idx)
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("multilinestring", None, idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("polygon", Some(tagged), size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end_geom("polygon", Some(tagged), idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("multipolygon", None, size, idx)
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("multipolygon", None, idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("geometrycollection", None, size, idx)
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("geometrycollection", None, idx)
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("circularstring", None, size, idx)
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("circularstring", None, idx)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("compoundcurve", None, size, idx)
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("compoundcurve", None, idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("curvepolygon", None, size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("curvepolygon", None, idx)
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("multicurve", None, size, idx)
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("multicurve", None, idx)
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("multisurface", None, size, idx)
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("multisurface", None, idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("triangle", Some(tagged), size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end_geom("triangle", Some(tagged), idx)
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("polyhedralsurface", None, size, idx)
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("polyhedralsurface", None, idx)
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin_geom("tin", None, size, idx)
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.end_geom("tin", None, idx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "with-geojson")]
+    fn trace_features() {
+        use crate::geojson::GeoJsonLineReader;
+        use crate::GeozeroDatasource;
+
+        let input = r#"{"type": "Feature", "properties": {"name": "a"}, "geometry": {"type": "Polygon", "coordinates": [[[0,0,1],[1,0,1],[0,0,1]]]}}"#;
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = TracingProcessor::with_dims(&mut out, CoordDimensions::xyz());
+        GeoJsonLineReader::new(input.as_bytes())
+            .process(&mut processor)
+            .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            r#"dataset_begin(None)
+  feature_begin(0)
+    properties_begin
+      property(0, "name", String("a"))
+    properties_end
+    geometry_begin
+      polygon_begin(tagged: true, size: 1, idx: 0)
+        linestring_begin(tagged: false, size: 3, idx: 0)
+          coordinate(0, 0, z: Some(1.0), m: None, t: None, tm: None, idx: 0)
+          coordinate(1, 0, z: Some(1.0), m: None, t: None, tm: None, idx: 1)
+          coordinate(0, 0, z: Some(1.0), m: None, t: None, tm: None, idx: 2)
+        linestring_end(tagged: false, idx: 0)
+      polygon_end(tagged: true, idx: 0)
+    geometry_end
+  feature_end(0)
+dataset_end
+"#
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::PropertyProcessor;
+
+/// Coordinate transformation applied by a [`TransformProcessor`]
+///
+/// Implemented for closures transforming `(x, y)` pairs.
+pub trait CoordTransform {
+    /// Transform a coordinate. `z` is `None` for coordinates without Z value.
+    fn transform(&mut self, x: f64, y: f64, z: Option<f64>) -> Result<(f64, f64, Option<f64>)>;
+    /// SRID of transformed coordinates, given the SRID of the input
+    fn transform_srid(&mut self, srid: Option<i32>) -> Result<Option<i32>> {
+        Ok(srid)
+    }
+}
+
+impl<F: FnMut(f64, f64) -> (f64, f64)> CoordTransform for F {
+    fn transform(&mut self, x: f64, y: f64, z: Option<f64>) -> Result<(f64, f64, Option<f64>)> {
+        let (x, y) = self(x, y);
+        Ok((x, y, z))
+    }
+}
+
+/// 2D affine transformation
+///
+/// `x' = a * x + b * y + xoff`, `y' = d * x + e * y + yoff`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine {
+    pub a: f64,
+    pub b: f64,
+    pub xoff: f64,
+    pub d: f64,
+    pub e: f64,
+    pub yoff: f64,
+}
+
+impl Affine {
+    pub fn new(a: f64, b: f64, xoff: f64, d: f64, e: f64, yoff: f64) -> Self {
+        Affine {
+            a,
+            b,
+            xoff,
+            d,
+            e,
+            yoff,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Affine::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0)
+    }
+
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Affine::new(1.0, 0.0, dx, 0.0, 1.0, dy)
+    }
+
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Affine::new(sx, 0.0, 0.0, 0.0, sy, 0.0)
+    }
+
+    /// Counter-clockwise rotation around the origin
+    pub fn rotate(degrees: f64) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Affine::new(cos, -sin, 0.0, sin, cos, 0.0)
+    }
+
+    /// Transformation applying `self` first, then `other`
+    pub fn then(&self, other: &Affine) -> Self {
+        Affine::new(
+            other.a * self.a + other.b * self.d,
+            other.a * self.b + other.b * self.e,
+            other.a * self.xoff + other.b * self.yoff + other.xoff,
+            other.d * self.a + other.e * self.d,
+            other.d * self.b + other.e * self.e,
+            other.d * self.xoff + other.e * self.yoff + other.yoff,
+        )
+    }
+
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.a * x + self.b * y + self.xoff,
+            self.d * x + self.e * y + self.yoff,
+        )
+    }
+}
+
+impl CoordTransform for Affine {
+    fn transform(&mut self, x: f64, y: f64, z: Option<f64>) -> Result<(f64, f64, Option<f64>)> {
+        let (x, y) = self.apply(x, y);
+        Ok((x, y, z))
+    }
+}
+
+/// Swap x and y axis, e.g. for converting between lat/lon and lon/lat order
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SwapXy;
+
+impl CoordTransform for SwapXy {
+    fn transform(&mut self, x: f64, y: f64, z: Option<f64>) -> Result<(f64, f64, Option<f64>)> {
+        Ok((y, x, z))
+    }
+}
+
+/// Processor applying a [`CoordTransform`] to all coordinates
+///
+/// All other events are forwarded unchanged.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::{GeozeroGeometry, SwapXy, TransformProcessor};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = TransformProcessor::new(WktWriter::new(&mut out), SwapXy);
+/// Wkt("LINESTRING(47 8,48 9)").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "LINESTRING(8 47,9 48)");
+///
+/// out.clear();
+/// let mut processor = TransformProcessor::new(WktWriter::new(&mut out), |x, y| (x * 2.0, y));
+/// Wkt("POINT(1 2)").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "POINT(2 2)");
+/// # }
+/// ```
+pub struct TransformProcessor<P, F> {
+    inner: P,
+    transform: F,
+}
+
+impl<P, F: CoordTransform> TransformProcessor<P, F> {
+    pub fn new(inner: P, transform: F) -> Self {
+        TransformProcessor { inner, transform }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P: FeatureProcessor, F: CoordTransform> FeatureProcessor for TransformProcessor<P, F> {
+    forward!(
+        inner => dataset_begin, schema, dataset_end, feature_begin, feature_end, properties_begin,
+        properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: PropertyProcessor, F: CoordTransform> PropertyProcessor for TransformProcessor<P, F> {
+    forward!(inner => property);
+}
+
+impl<P: GeomProcessor, F: CoordTransform> GeomProcessor for TransformProcessor<P, F> {
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        let srid = self.transform.transform_srid(srid)?;
+        self.inner.srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let (x, y, _) = self.transform.transform(x, y, None)?;
+        self.inner.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let (x, y, z) = self.transform.transform(x, y, z)?;
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+    forward!(
+        inner => dimensions, multi_dim, supports_curves, empty_point, point_begin, point_end,
+        multipoint_begin, multipoint_end, linestring_begin, linestring_end, multilinestring_begin,
+        multilinestring_end, polygon_begin, polygon_end, multipolygon_begin, multipolygon_end,
+        geometrycollection_begin, geometrycollection_end, circularstring_begin, circularstring_end,
+        compoundcurve_begin, compoundcurve_end, curvepolygon_begin, curvepolygon_end,
+        multicurve_begin, multicurve_end, multisurface_begin, multisurface_end, triangle_begin,
+        triangle_end, polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn affine() {
+        let translate = Affine::translate(10.0, 20.0);
+        assert_eq!(translate.apply(1.0, 2.0), (11.0, 22.0));
+        let scale = Affine::scale(2.0, 3.0);
+        assert_eq!(scale.apply(1.0, 2.0), (2.0, 6.0));
+        assert_eq!(scale.then(&translate).apply(1.0, 2.0), (12.0, 26.0));
+        assert_eq!(translate.then(&scale).apply(1.0, 2.0), (22.0, 66.0));
+        let (x, y) = Affine::rotate(90.0).apply(1.0, 0.0);
+        assert!((x - 0.0).abs() < 1e-12 && (y - 1.0).abs() < 1e-12);
+        assert_eq!(Affine::identity().apply(1.5, 2.5), (1.5, 2.5));
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn transform_geometries() {
+        use crate::wkt::{Wkt, WktWriter};
+        use crate::GeozeroGeometry;
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor =
+            TransformProcessor::new(WktWriter::new(&mut out), Affine::translate(1.0, 2.0));
+        Wkt("POLYGON((0 0,1 0,1 1,0 0))")
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "POLYGON((1 2,2 2,2 3,1 2))"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "with-geojson", feature = "with-wkt"))]
+    fn transform_z() {
+        use crate::geojson::GeoJson;
+        use crate::wkt::WktWriter;
+        use crate::{CoordDimensions, GeozeroGeometry};
+
+        // Z values are passed through
+        let mut out: Vec<u8> = Vec::new();
+        let writer = WktWriter::with_dims(&mut out, CoordDimensions::xyz());
+        let mut processor = TransformProcessor::new(writer, SwapXy);
+        GeoJson(r#"{"type": "Point", "coordinates": [1, 2, 3]}"#)
+            .process_geom(&mut processor)
+            .unwrap();
+        assert_eq!(std::str::from_utf8(&out).unwrap(), "POINT(2 1 3)");
+    }
+}
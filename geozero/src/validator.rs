@@ -0,0 +1,816 @@
+use crate::error::{GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::forward;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use crate::schema::{GeometryType, Schema};
+use std::fmt;
+
+/// Violation of the processing protocol found by a [`ValidatingProcessor`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// Position of the offending event in the stream, starting with 1
+    pub event: usize,
+    /// Open feature and geometries, e.g. `feature 2 > geometry > Polygon[0] > LineString[1]`
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "event {}: {}", self.event, self.message)
+        } else {
+            write!(f, "event {} in {}: {}", self.event, self.path, self.message)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Section {
+    None,
+    Properties,
+    Geometry,
+}
+
+struct FeatureState {
+    idx: u64,
+    section: Section,
+    properties_done: bool,
+    geometry_done: bool,
+    next_property_idx: usize,
+}
+
+struct Frame {
+    kind: GeometryType,
+    tagged: bool,
+    size: usize,
+    idx: usize,
+    count: usize,
+}
+
+impl Frame {
+    fn describe(&self) -> String {
+        describe(self.kind, self.tagged)
+    }
+}
+
+fn describe(kind: GeometryType, tagged: bool) -> String {
+    if tagged {
+        format!("{kind:?}")
+    } else {
+        format!("untagged {kind:?}")
+    }
+}
+
+fn takes_coordinates(kind: GeometryType) -> bool {
+    matches!(
+        kind,
+        GeometryType::Point
+            | GeometryType::MultiPoint
+            | GeometryType::LineString
+            | GeometryType::CircularString
+    )
+}
+
+/// Allowed nesting of geometries, as documented in [`GeomProcessor`]
+fn child_allowed(parent: Option<GeometryType>, kind: GeometryType, tagged: bool) -> bool {
+    use GeometryType::*;
+    match parent {
+        None | Some(GeometryCollection) => tagged,
+        Some(MultiLineString | Polygon | Triangle) => kind == LineString && !tagged,
+        Some(MultiPolygon | PolyhedralSurface) => kind == Polygon && !tagged,
+        Some(CompoundCurve) => kind == CircularString || (kind == LineString && !tagged),
+        Some(CurvePolygon | MultiCurve) => {
+            matches!(kind, CircularString | CompoundCurve) || (kind == LineString && !tagged)
+        }
+        Some(MultiSurface) => kind == CurvePolygon || (kind == Polygon && !tagged),
+        Some(Tin) => matches!(kind, Triangle | Polygon) && !tagged,
+        Some(Point | MultiPoint | LineString | CircularString | Unknown) => false,
+    }
+}
+
+/// Processor checking the event stream against the processing protocol
+///
+/// Checks begin/end nesting, declared sizes against actual counts, idx sequencing
+/// and the order of dataset, feature, property and geometry events.
+/// All events are forwarded to the wrapped processor.
+///
+/// # Usage example:
+///
+/// ```rust
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::wkt::{Wkt, WktWriter};
+/// use geozero::{GeozeroGeometry, ValidatingProcessor};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = ValidatingProcessor::new(WktWriter::new(&mut out));
+/// Wkt("POLYGON((0 0,1 0,1 1,0 0))").process_geom(&mut processor).unwrap();
+/// processor.finish().unwrap();
+/// # }
+/// ```
+pub struct ValidatingProcessor<P> {
+    inner: P,
+    fail_fast: bool,
+    violations: Vec<Violation>,
+    event: usize,
+    dataset_begun: bool,
+    dataset_ended: bool,
+    schema_seen: bool,
+    next_feature_idx: u64,
+    feature: Option<FeatureState>,
+    stack: Vec<Frame>,
+}
+
+impl<P> ValidatingProcessor<P> {
+    /// Validating processor failing with [`GeozeroError::Validation`] on the first violation
+    pub fn new(inner: P) -> Self {
+        ValidatingProcessor {
+            inner,
+            fail_fast: true,
+            violations: Vec::new(),
+            event: 0,
+            dataset_begun: false,
+            dataset_ended: false,
+            schema_seen: false,
+            next_feature_idx: 0,
+            feature: None,
+            stack: Vec::new(),
+        }
+    }
+
+    /// Validating processor collecting all violations without failing
+    pub fn collecting(inner: P) -> Self {
+        ValidatingProcessor {
+            fail_fast: false,
+            ..Self::new(inner)
+        }
+    }
+
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Check for unclosed datasets, features and geometries at the end of the stream
+    pub fn finish(&mut self) -> Result<()> {
+        self.event += 1;
+        if let Some(frame) = self.stack.last() {
+            let msg = format!("end of stream with open {}", frame.describe());
+            return self.violation(msg);
+        }
+        if let Some(feature) = &self.feature {
+            let msg = format!("end of stream with open feature {}", feature.idx);
+            return self.violation(msg);
+        }
+        if self.dataset_begun && !self.dataset_ended {
+            return self.violation("end of stream without dataset_end".to_string());
+        }
+        Ok(())
+    }
+
+    fn path(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(feature) = &self.feature {
+            parts.push(format!("feature {}", feature.idx));
+            match feature.section {
+                Section::None => {}
+                Section::Properties => parts.push("properties".to_string()),
+                Section::Geometry => parts.push("geometry".to_string()),
+            }
+        }
+        parts.extend(
+            self.stack
+                .iter()
+                .map(|frame| format!("{:?}[{}]", frame.kind, frame.idx)),
+        );
+        parts.join(" > ")
+    }
+
+    fn violation(&mut self, message: String) -> Result<()> {
+        let violation = Violation {
+            event: self.event,
+            path: self.path(),
+            message,
+        };
+        if self.fail_fast {
+            let err = GeozeroError::Validation(violation.to_string());
+            self.violations.push(violation);
+            Err(err)
+        } else {
+            self.violations.push(violation);
+            Ok(())
+        }
+    }
+
+    fn next_event(&mut self, name: &str) -> Result<()> {
+        self.event += 1;
+        if self.dataset_ended {
+            return self.violation(format!("{name} after dataset_end"));
+        }
+        Ok(())
+    }
+
+    fn feature_section(&mut self, name: &str) -> Result<Option<&mut FeatureState>> {
+        if self.feature.is_none() {
+            self.violation(format!("{name} outside of feature"))?;
+        }
+        Ok(self.feature.as_mut())
+    }
+
+    fn geometry_event(&mut self, name: &str) -> Result<()> {
+        self.next_event(name)?;
+        match &self.feature {
+            Some(feature) if feature.section != Section::Geometry => {
+                self.violation(format!("{name} outside of geometry_begin/geometry_end"))
+            }
+            None if self.dataset_begun => self.violation(format!("{name} outside of feature")),
+            _ => Ok(()),
+        }
+    }
+
+    fn child_idx(&mut self, desc: &str, idx: usize) -> Result<()> {
+        if let Some(parent) = self.stack.last_mut() {
+            let expected = parent.count;
+            parent.count += 1;
+            if idx != expected {
+                return self.violation(format!("{desc} idx {idx}, expected {expected}"));
+            }
+        }
+        Ok(())
+    }
+
+    fn begin(&mut self, kind: GeometryType, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.geometry_event(&format!("{kind:?}_begin").to_lowercase())?;
+        let desc = describe(kind, tagged);
+        let parent = self.stack.last().map(|frame| frame.kind);
+        if !child_allowed(parent, kind, tagged) {
+            match parent {
+                Some(parent) => self.violation(format!("{desc} not allowed in {parent:?}"))?,
+                None => self.violation(format!("{desc} outside of a containing geometry"))?,
+            }
+        }
+        self.child_idx(&desc, idx)?;
+        self.stack.push(Frame {
+            kind,
+            tagged,
+            size,
+            idx,
+            count: 0,
+        });
+        Ok(())
+    }
+
+    fn end(&mut self, kind: GeometryType, tagged: bool, idx: usize) -> Result<()> {
+        self.geometry_event(&format!("{kind:?}_end").to_lowercase())?;
+        let desc = describe(kind, tagged);
+        let Some(frame) = self.stack.last() else {
+            return self.violation(format!("end of {desc} without begin"));
+        };
+        if frame.kind != kind || frame.tagged != tagged {
+            let msg = format!("end of {desc}, expected end of {}", frame.describe());
+            return self.violation(msg);
+        }
+        let (open_idx, size, count) = (frame.idx, frame.size, frame.count);
+        if idx != open_idx {
+            self.violation(format!("end of {desc} with idx {idx}, expected {open_idx}"))?;
+        }
+        if count != size {
+            let msg = if kind == GeometryType::Point {
+                format!("Point with {count} coordinates, expected 1")
+            } else {
+                let children = if takes_coordinates(kind) {
+                    "coordinates"
+                } else {
+                    "geometries"
+                };
+                format!("{desc} declared size {size}, found {count} {children}")
+            };
+            self.violation(msg)?;
+        }
+        self.stack.pop();
+        Ok(())
+    }
+
+    fn coord(&mut self, name: &str, idx: usize) -> Result<()> {
+        self.geometry_event(name)?;
+        let Some(frame) = self.stack.last_mut() else {
+            return self.violation(format!("{name} outside of geometry"));
+        };
+        if !takes_coordinates(frame.kind) {
+            let msg = format!("{name} not allowed in {}", frame.describe());
+            return self.violation(msg);
+        }
+        let expected = frame.count;
+        frame.count += 1;
+        if frame.kind != GeometryType::Point && idx != expected {
+            return self.violation(format!("{name} idx {idx}, expected {expected}"));
+        }
+        Ok(())
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for ValidatingProcessor<P> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.next_event("dataset_begin")?;
+        if self.dataset_begun {
+            self.violation("dataset_begin called twice".to_string())?;
+        }
+        self.dataset_begun = true;
+        self.inner.dataset_begin(name)
+    }
+    fn schema(&mut self, schema: &Schema) -> Result<()> {
+        self.next_event("schema")?;
+        if !self.dataset_begun {
+            self.violation("schema before dataset_begin".to_string())?;
+        } else if self.schema_seen {
+            self.violation("schema emitted twice".to_string())?;
+        } else if self.feature.is_some() || self.next_feature_idx > 0 {
+            self.violation("schema after first feature".to_string())?;
+        }
+        self.schema_seen = true;
+        self.inner.schema(schema)
+    }
+    fn dataset_end(&mut self) -> Result<()> {
+        self.next_event("dataset_end")?;
+        if !self.dataset_begun {
+            self.violation("dataset_end without dataset_begin".to_string())?;
+        } else if self.feature.is_some() {
+            self.violation("dataset_end before feature_end".to_string())?;
+        }
+        self.dataset_ended = true;
+        self.inner.dataset_end()
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        self.next_event("feature_begin")?;
+        if !self.dataset_begun {
+            self.violation("feature_begin before dataset_begin".to_string())?;
+        }
+        if self.feature.is_some() {
+            self.violation("feature_begin before feature_end".to_string())?;
+        }
+        if idx != self.next_feature_idx {
+            let msg = format!("feature idx {idx}, expected {}", self.next_feature_idx);
+            self.violation(msg)?;
+        }
+        self.next_feature_idx = idx + 1;
+        self.stack.clear();
+        self.feature = Some(FeatureState {
+            idx,
+            section: Section::None,
+            properties_done: false,
+            geometry_done: false,
+            next_property_idx: 0,
+        });
+        self.inner.feature_begin(idx)
+    }
+    fn feature_end(&mut self, idx: u64) -> Result<()> {
+        self.next_event("feature_end")?;
+        match &self.feature {
+            None => self.violation("feature_end without feature_begin".to_string())?,
+            Some(feature) => {
+                let (open_idx, section) = (feature.idx, feature.section);
+                if idx != open_idx {
+                    self.violation(format!("feature_end idx {idx}, expected {open_idx}"))?;
+                }
+                match section {
+                    Section::None => {}
+                    Section::Properties => {
+                        self.violation("feature_end before properties_end".to_string())?;
+                    }
+                    Section::Geometry => {
+                        self.violation("feature_end before geometry_end".to_string())?;
+                    }
+                }
+            }
+        }
+        self.feature = None;
+        self.stack.clear();
+        self.inner.feature_end(idx)
+    }
+    fn properties_begin(&mut self) -> Result<()> {
+        self.next_event("properties_begin")?;
+        if let Some(feature) = self.feature_section("properties_begin")? {
+            let msg = if feature.section != Section::None {
+                Some("properties_begin inside another section")
+            } else if feature.properties_done {
+                Some("properties emitted twice")
+            } else {
+                feature.section = Section::Properties;
+                None
+            };
+            if let Some(msg) = msg {
+                self.violation(msg.to_string())?;
+            }
+        }
+        self.inner.properties_begin()
+    }
+    fn properties_end(&mut self) -> Result<()> {
+        self.next_event("properties_end")?;
+        if let Some(feature) = self.feature_section("properties_end")? {
+            if feature.section == Section::Properties {
+                feature.section = Section::None;
+                feature.properties_done = true;
+            } else {
+                self.violation("properties_end without properties_begin".to_string())?;
+            }
+        }
+        self.inner.properties_end()
+    }
+    fn geometry_begin(&mut self) -> Result<()> {
+        self.next_event("geometry_begin")?;
+        if let Some(feature) = self.feature_section("geometry_begin")? {
+            let msg = if feature.section != Section::None {
+                Some("geometry_begin inside another section")
+            } else if feature.geometry_done {
+                Some("geometry emitted twice")
+            } else {
+                feature.section = Section::Geometry;
+                None
+            };
+            if let Some(msg) = msg {
+                self.violation(msg.to_string())?;
+            }
+        }
+        self.inner.geometry_begin()
+    }
+    fn geometry_end(&mut self) -> Result<()> {
+        self.next_event("geometry_end")?;
+        if let Some(frame) = self.stack.last() {
+            let msg = format!("geometry_end with open {}", frame.describe());
+            self.violation(msg)?;
+            self.stack.clear();
+        }
+        if let Some(feature) = self.feature_section("geometry_end")? {
+            if feature.section == Section::Geometry {
+                feature.section = Section::None;
+                feature.geometry_done = true;
+            } else {
+                self.violation("geometry_end without geometry_begin".to_string())?;
+            }
+        }
+        self.inner.geometry_end()
+    }
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for ValidatingProcessor<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.next_event("property")?;
+        if let Some(feature) = self.feature.as_mut() {
+            let (section, expected) = (feature.section, feature.next_property_idx);
+            feature.next_property_idx = idx + 1;
+            if section != Section::Properties {
+                self.violation(format!(
+                    "property `{name}` outside of properties_begin/properties_end"
+                ))?;
+            } else if idx != expected {
+                self.violation(format!("property `{name}` idx {idx}, expected {expected}"))?;
+            }
+        }
+        self.inner.property(idx, name, value)
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for ValidatingProcessor<P> {
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.geometry_event("srid")?;
+        if let Some(frame) = self.stack.last() {
+            let msg = format!("srid inside {}", frame.describe());
+            self.violation(msg)?;
+        }
+        self.inner.srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coord("xy", idx)?;
+        self.inner.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.coord("coordinate", idx)?;
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        if self.stack.last().map(|frame| frame.kind) == Some(GeometryType::MultiPoint) {
+            self.coord("empty_point", idx)?;
+        } else {
+            self.begin(GeometryType::Point, true, 0, idx)?;
+            self.event -= 1;
+            self.end(GeometryType::Point, true, idx)?;
+        }
+        self.inner.empty_point(idx)
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.begin(GeometryType::Point, true, 1, idx)?;
+        self.inner.point_begin(idx)
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::Point, true, idx)?;
+        self.inner.point_end(idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiPoint, true, size, idx)?;
+        self.inner.multipoint_begin(size, idx)
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::MultiPoint, true, idx)?;
+        self.inner.multipoint_end(idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::LineString, tagged, size, idx)?;
+        self.inner.linestring_begin(tagged, size, idx)
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end(GeometryType::LineString, tagged, idx)?;
+        self.inner.linestring_end(tagged, idx)
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiLineString, true, size, idx)?;
+        self.inner.multilinestring_begin(size, idx)
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::MultiLineString, true, idx)?;
+        self.inner.multilinestring_end(idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::Polygon, tagged, size, idx)?;
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end(GeometryType::Polygon, tagged, idx)?;
+        self.inner.polygon_end(tagged, idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiPolygon, true, size, idx)?;
+        self.inner.multipolygon_begin(size, idx)
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::MultiPolygon, true, idx)?;
+        self.inner.multipolygon_end(idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::GeometryCollection, true, size, idx)?;
+        self.inner.geometrycollection_begin(size, idx)
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::GeometryCollection, true, idx)?;
+        self.inner.geometrycollection_end(idx)
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::CircularString, true, size, idx)?;
+        self.inner.circularstring_begin(size, idx)
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::CircularString, true, idx)?;
+        self.inner.circularstring_end(idx)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::CompoundCurve, true, size, idx)?;
+        self.inner.compoundcurve_begin(size, idx)
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::CompoundCurve, true, idx)?;
+        self.inner.compoundcurve_end(idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::CurvePolygon, true, size, idx)?;
+        self.inner.curvepolygon_begin(size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::CurvePolygon, true, idx)?;
+        self.inner.curvepolygon_end(idx)
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiCurve, true, size, idx)?;
+        self.inner.multicurve_begin(size, idx)
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::MultiCurve, true, idx)?;
+        self.inner.multicurve_end(idx)
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiSurface, true, size, idx)?;
+        self.inner.multisurface_begin(size, idx)
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::MultiSurface, true, idx)?;
+        self.inner.multisurface_end(idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::Triangle, tagged, size, idx)?;
+        self.inner.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end(GeometryType::Triangle, tagged, idx)?;
+        self.inner.triangle_end(tagged, idx)
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::PolyhedralSurface, true, size, idx)?;
+        self.inner.polyhedralsurface_begin(size, idx)
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::PolyhedralSurface, true, idx)?;
+        self.inner.polyhedralsurface_end(idx)
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::Tin, true, size, idx)?;
+        self.inner.tin_begin(size, idx)
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::Tin, true, idx)?;
+        self.inner.tin_end(idx)
+    }
+    forward!(inner => dimensions, multi_dim, supports_curves);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{FeatureRecorder, ProcessorSink};
+
+    fn validate<F>(events: F) -> Vec<String>
+    where
+        F: FnOnce(&mut ValidatingProcessor<FeatureRecorder>) -> Result<()>,
+    {
+        let mut processor = ValidatingProcessor::collecting(FeatureRecorder::new());
+        events(&mut processor).unwrap();
+        processor.finish().unwrap();
+        processor
+            .violations()
+            .iter()
+            .map(|v| v.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn valid_geometries() {
+        let violations = validate(|p| {
+            p.srid(Some(4326))?;
+            p.polygon_begin(true, 1, 0)?;
+            p.linestring_begin(false, 3, 0)?;
+            p.xy(0.0, 0.0, 0)?;
+            p.xy(1.0, 0.0, 1)?;
+            p.xy(0.0, 0.0, 2)?;
+            p.linestring_end(false, 0)?;
+            p.polygon_end(true, 0)
+        });
+        assert!(violations.is_empty(), "{violations:?}");
+
+        let violations = validate(|p| {
+            p.geometrycollection_begin(2, 0)?;
+            p.empty_point(0)?;
+            p.multipoint_begin(2, 1)?;
+            p.xy(0.0, 0.0, 0)?;
+            p.empty_point(1)?;
+            p.multipoint_end(1)?;
+            p.geometrycollection_end(0)
+        });
+        assert!(violations.is_empty(), "{violations:?}");
+    }
+
+    #[test]
+    fn geometry_violations() {
+        let violations = validate(|p| {
+            p.multipoint_begin(2, 0)?;
+            p.point_begin(0)?;
+            p.xy(0.0, 0.0, 0)?;
+            p.point_end(0)?;
+            p.multipoint_end(0)
+        });
+        assert_eq!(
+            violations,
+            [
+                "event 2 in MultiPoint[0]: Point not allowed in MultiPoint",
+                "event 5 in MultiPoint[0]: MultiPoint declared size 2, found 1 coordinates",
+            ]
+        );
+
+        let violations = validate(|p| {
+            p.linestring_begin(false, 2, 0)?;
+            p.xy(0.0, 0.0, 0)?;
+            p.xy(1.0, 0.0, 2)?;
+            p.polygon_end(true, 0)?;
+            p.linestring_end(false, 0)
+        });
+        assert_eq!(
+            violations,
+            [
+                "event 1: untagged LineString outside of a containing geometry",
+                "event 3 in LineString[0]: xy idx 2, expected 1",
+                "event 4 in LineString[0]: end of Polygon, expected end of untagged LineString",
+            ]
+        );
+
+        let violations = validate(|p| {
+            p.multipolygon_begin(1, 0)?;
+            p.polygon_begin(false, 0, 1)?;
+            p.polygon_end(false, 1)?;
+            p.multipolygon_end(0)?;
+            p.srid(None)?;
+            p.point_begin(0)
+        });
+        assert_eq!(
+            violations,
+            [
+                "event 2 in MultiPolygon[0]: untagged Polygon idx 1, expected 0",
+                "event 7 in Point[0]: end of stream with open Point",
+            ]
+        );
+    }
+
+    #[test]
+    fn feature_violations() {
+        let violations = validate(|p| {
+            p.dataset_begin(None)?;
+            p.feature_begin(0)?;
+            p.properties_begin()?;
+            p.property(0, "a", &ColumnValue::Int(1))?;
+            p.property(2, "b", &ColumnValue::Int(2))?;
+            p.properties_end()?;
+            p.geometry_begin()?;
+            p.xy(0.0, 0.0, 0)?;
+            p.geometry_end()?;
+            p.point_begin(0)?;
+            p.feature_end(0)?;
+            p.feature_begin(2)?;
+            p.feature_end(1)?;
+            p.schema(&Schema::default())?;
+            p.dataset_end()?;
+            p.dataset_end()
+        });
+        assert_eq!(
+            violations,
+            [
+                "event 5 in feature 0 > properties: property `b` idx 2, expected 1",
+                "event 8 in feature 0 > geometry: xy outside of geometry",
+                "event 10 in feature 0: point_begin outside of geometry_begin/geometry_end",
+                "event 12: feature idx 2, expected 1",
+                "event 13 in feature 2: feature_end idx 1, expected 2",
+                "event 14: schema after first feature",
+                "event 16: dataset_end after dataset_end",
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "with-geojson", feature = "with-wkb", feature = "with-wkt"))]
+    fn valid_reader_streams() {
+        use crate::geojson::GeoJsonLineReader;
+        use crate::wkb::Ewkb;
+        use crate::wkt::WktWriter;
+        use crate::{GeozeroDatasource, GeozeroGeometry};
+
+        let input = r#"{"type": "Feature", "properties": {"a": 1, "b": "x"}, "geometry": {"type": "MultiPolygon", "coordinates": [[[[0,0],[1,0],[1,1],[0,0]]],[[[2,2],[3,2],[3,3],[2,2]]]]}}
+{"type": "Feature", "properties": {"a": 2}, "geometry": {"type": "GeometryCollection", "geometries": [{"type": "Point", "coordinates": [1,2]}, {"type": "LineString", "coordinates": [[1,2],[3,4]]}]}}"#;
+        let mut processor = ValidatingProcessor::new(ProcessorSink::new());
+        GeoJsonLineReader::new(input.as_bytes())
+            .process(&mut processor)
+            .unwrap();
+        processor.finish().unwrap();
+
+        let geometries = [
+            // CURVEPOLYGON(COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,3 0,3 -1,0 -1,0 0)))
+            "010A0000000100000001090000000200000001080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F0000000000000040000000000000000001020000000500000000000000000000400000000000000000000000000000084000000000000000000000000000000840000000000000F0BF0000000000000000000000000000F0BF00000000000000000000000000000000",
+            // MULTICURVE((0 0, 5 5),CIRCULARSTRING(4 0, 4 4, 8 4))
+            "010B000000020000000102000000020000000000000000000000000000000000000000000000000014400000000000001440010800000003000000000000000000104000000000000000000000000000001040000000000000104000000000000020400000000000001040",
+            // TIN(((0 0 0,0 0 1,0 1 0,0 0 0)),((0 0 0,0 1 0,1 1 0,0 0 0)))
+            "0110000080020000000111000080010000000400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000F03F0000000000000000000000000000F03F0000000000000000000000000000000000000000000000000000000000000000011100008001000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000F03F0000000000000000000000000000F03F000000000000F03F0000000000000000000000000000000000000000000000000000000000000000",
+        ];
+        for ewkb in geometries {
+            let mut out: Vec<u8> = Vec::new();
+            let mut processor = ValidatingProcessor::new(WktWriter::new(&mut out));
+            Ewkb(hex::decode(ewkb).unwrap())
+                .process_geom(&mut processor)
+                .unwrap();
+            processor.finish().unwrap();
+            assert!(!out.is_empty());
+        }
+    }
+
+    #[test]
+    fn fail_fast() {
+        let mut processor = ValidatingProcessor::new(ProcessorSink::new());
+        processor.linestring_begin(true, 1, 0).unwrap();
+        let err = processor.linestring_end(true, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid event stream: event 2 in LineString[0]: LineString declared size 1, found 0 coordinates"
+        );
+        assert_eq!(processor.violations().len(), 1);
+    }
+}
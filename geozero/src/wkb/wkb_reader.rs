@@ -1,6 +1,6 @@
 use crate::error::{GeozeroError, Result};
 use crate::wkb::{WKBGeometryType, WkbDialect};
-use crate::{GeomProcessor, GeozeroGeometry};
+use crate::{ArcTolerance, GeomProcessor, GeozeroGeometry, LinearizeProcessor, ProcessorRef};
 use scroll::ctx::{FromCtx, SizeWith};
 use scroll::{Endian, IOread};
 use std::io::Read;
@@ -65,21 +65,21 @@ impl<B: AsRef<[u8]>> GeozeroGeometry for MySQLWkb<B> {
 pub fn process_wkb_geom<R: Read, P: GeomProcessor>(raw: &mut R, processor: &mut P) -> Result<()> {
     let info = read_wkb_header(raw)?;
     processor.srid(info.srid)?;
-    process_wkb_geom_n(raw, &info, read_wkb_nested_header, 0, processor)
+    process_wkb_root(raw, &info, read_wkb_nested_header, processor)
 }
 
 /// Process EWKB geometry.
 pub fn process_ewkb_geom<R: Read, P: GeomProcessor>(raw: &mut R, processor: &mut P) -> Result<()> {
     let info = read_ewkb_header(raw)?;
     processor.srid(info.srid)?;
-    process_wkb_geom_n(raw, &info, read_ewkb_nested_header, 0, processor)
+    process_wkb_root(raw, &info, read_ewkb_nested_header, processor)
 }
 
 /// Process GPKG geometry.
 pub fn process_gpkg_geom<R: Read, P: GeomProcessor>(raw: &mut R, processor: &mut P) -> Result<()> {
     let info = read_gpkg_header(raw)?;
     processor.srid(info.srid)?;
-    process_wkb_geom_n(raw, &info, read_wkb_nested_header, 0, processor)
+    process_wkb_root(raw, &info, read_wkb_nested_header, processor)
 }
 
 /// Process MySQL WKB geometry.
@@ -89,14 +89,14 @@ pub fn process_spatialite_geom<R: Read, P: GeomProcessor>(
 ) -> Result<()> {
     let info = read_spatialite_header(raw)?;
     processor.srid(info.srid)?;
-    process_wkb_geom_n(raw, &info, read_spatialite_nested_header, 0, processor)
+    process_wkb_root(raw, &info, read_spatialite_nested_header, processor)
 }
 
 /// Process MySQL WKB geometry.
 pub fn process_mysql_geom<R: Read, P: GeomProcessor>(raw: &mut R, processor: &mut P) -> Result<()> {
     let info = read_mysql_header(raw)?;
     processor.srid(info.srid)?;
-    process_wkb_geom_n(raw, &info, read_wkb_nested_header, 0, processor)
+    process_wkb_root(raw, &info, read_wkb_nested_header, processor)
 }
 
 /// Process WKB type geometry..
@@ -114,6 +114,22 @@ pub fn process_wkb_type_geom<R: Read, P: GeomProcessor>(
     }
 }
 
+/// Process root geometry, approximating curves for processors without curve support.
+fn process_wkb_root<R: Read, P: GeomProcessor>(
+    raw: &mut R,
+    info: &WkbInfo,
+    read_header: fn(&mut R, info: &WkbInfo) -> Result<WkbInfo>,
+    processor: &mut P,
+) -> Result<()> {
+    if processor.supports_curves() {
+        process_wkb_geom_n(raw, info, read_header, 0, processor)
+    } else {
+        let mut linearize =
+            LinearizeProcessor::new(ProcessorRef(processor), ArcTolerance::default());
+        process_wkb_geom_n(raw, info, read_header, 0, &mut linearize)
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct WkbInfo {
     endian: Endian,
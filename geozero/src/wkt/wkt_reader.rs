@@ -1,5 +1,7 @@
 use crate::error::{GeozeroError, Result};
-use crate::{FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry};
+use crate::{
+    FeatureIter, FeatureProcessor, FeatureSource, GeomProcessor, GeozeroDatasource, GeozeroGeometry,
+};
 
 use std::io::Read;
 use wkt::types::{Coord, LineString, Polygon};
@@ -62,7 +64,7 @@ pub struct EwktString(pub String);
 pub struct EwktStr<'a>(pub &'a str);
 
 /// Wkt Reader.
-pub struct WktReader<R: Read>(pub R);
+pub struct WktReader<R>(pub R);
 
 impl<R: Read> GeozeroDatasource for WktReader<R> {
     fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
@@ -70,6 +72,45 @@ impl<R: Read> GeozeroDatasource for WktReader<R> {
     }
 }
 
+impl<R: Read> WktReader<R> {
+    /// Iterate over owned features, i.e. the single WKT geometry of the input
+    pub fn features(self) -> FeatureIter<Self> {
+        FeatureIter::new(self)
+    }
+}
+
+impl<R: Read> FeatureSource for WktReader<R> {
+    fn process_next_feature<P: FeatureProcessor>(
+        &mut self,
+        idx: u64,
+        processor: &mut P,
+    ) -> Result<bool> {
+        let mut wkt_string = String::new();
+        self.0.read_to_string(&mut wkt_string)?;
+        if wkt_string.trim().is_empty() {
+            return Ok(false);
+        }
+        processor.feature_begin(idx)?;
+        processor.geometry_begin()?;
+        read_wkt(&mut wkt_string.as_bytes(), processor)?;
+        processor.geometry_end()?;
+        processor.feature_end(idx)?;
+        Ok(true)
+    }
+}
+
+/// Reads the whole input before parsing, it is not streamed.
+#[cfg(feature = "with-tokio")]
+#[async_trait::async_trait]
+impl<R: tokio::io::AsyncRead + Unpin + Send> crate::AsyncGeozeroDatasource for WktReader<R> {
+    async fn process<P: FeatureProcessor + Send>(&mut self, processor: &mut P) -> Result<()> {
+        use tokio::io::AsyncReadExt;
+        let mut wkt_string = String::new();
+        self.0.read_to_string(&mut wkt_string).await?;
+        read_wkt(&mut wkt_string.as_bytes(), processor)
+    }
+}
+
 /// Read and process WKT geometry.
 pub fn read_wkt<R: Read, P: GeomProcessor>(reader: &mut R, processor: &mut P) -> Result<()> {
     use std::str::FromStr;
@@ -189,6 +230,18 @@ mod test {
     use crate::ToWkt;
     use geo_types::{line_string, point, polygon};
 
+    #[test]
+    fn feature_iter() {
+        let features = WktReader("POINT(1.0 2.0)".as_bytes())
+            .features()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].to_wkt().unwrap(), "POINT(1 2)");
+
+        assert_eq!(WktReader("".as_bytes()).features().count(), 0);
+    }
+
     #[test]
     fn point() {
         let wkt = Wkt("POINT(1.0 2.0)");
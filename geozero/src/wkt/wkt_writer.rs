@@ -1,5 +1,5 @@
 use crate::error::Result;
-use crate::{CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
+use crate::{CoordDimensions, CoordPrecision, FeatureProcessor, GeomProcessor, PropertyProcessor};
 use std::io::Write;
 use std::vec;
 
@@ -10,6 +10,7 @@ pub struct WktWriter<W: Write> {
     dims: CoordDimensions,
     srid: Option<i32>,
     dialect: WktDialect,
+    precision: CoordPrecision,
     first_header: bool,
     /// Stack of in-progress geometry sizes
     geometry_sizes: Vec<usize>,
@@ -35,12 +36,19 @@ impl<W: Write> WktWriter<W> {
             dims,
             srid,
             dialect,
+            precision: CoordPrecision::default(),
             first_header: true,
             geometry_sizes: vec![],
             out,
         }
     }
 
+    /// Write coordinates with the given precision
+    pub fn with_precision(mut self, precision: CoordPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+
     fn header(&mut self, srid: Option<i32>) -> Result<()> {
         if self.first_header && self.dialect == WktDialect::Ewkt {
             self.first_header = false;
@@ -101,6 +109,7 @@ impl<W: Write> GeomProcessor for WktWriter<W> {
         if f64::is_nan(x) && f64::is_nan(y) {
             self.out.write_all(b"EMPTY")?;
         } else {
+            let (x, y) = (self.precision.format(x), self.precision.format(y));
             self.out.write_all(format!("{x} {y}").as_bytes())?;
         }
         Ok(())
@@ -124,11 +133,14 @@ impl<W: Write> GeomProcessor for WktWriter<W> {
         {
             self.out.write_all(b"EMPTY")?;
         } else {
+            let (x, y) = (self.precision.format(x), self.precision.format(y));
             self.out.write_all(format!("{x} {y}").as_bytes())?;
             if let Some(z) = z {
+                let z = self.precision.format(z);
                 self.out.write_all(format!(" {z}").as_bytes())?;
             }
             if let Some(m) = m {
+                let m = self.precision.format(m);
                 self.out.write_all(format!(" {m}").as_bytes())?;
             }
         }
@@ -259,4 +271,27 @@ mod test {
         let ewkt = Ewkt::from_wkb(&mut cursor, WkbDialect::Ewkb).unwrap();
         assert_eq!(ewkt.0, "SRID=4326;MULTIPOINT(10 -20 100,0 -0.5 101)")
     }
+
+    #[test]
+    fn precision() {
+        use crate::wkt::{Wkt, WktWriter};
+        use crate::{CoordPrecision, GeozeroGeometry};
+
+        let wkt = Wkt("LINESTRING(8.530000000000001 -0.0001,10 2.26)");
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out).with_precision(CoordPrecision::decimals(2));
+        wkt.process_geom(&mut writer).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "LINESTRING(8.53 0.00,10.00 2.26)"
+        );
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = WktWriter::new(&mut out).with_precision(CoordPrecision::trimmed(1));
+        wkt.process_geom(&mut writer).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "LINESTRING(8.5 0,10 2.3)"
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use geozero::mvt::{Message, Tile};
 use geozero::{
     ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, GeozeroDatasource,
-    PropertyProcessor, ToJson, ToMvt,
+    PropertyProcessor, Schema, ToJson, ToMvt,
 };
 use serde_json::json;
 use std::env;
@@ -265,6 +265,12 @@ impl FeatureProcessor for Proc {
         Ok(())
     }
 
+    fn schema(&mut self, schema: &Schema) -> geozero::error::Result<()> {
+        let columns: Vec<_> = schema.columns.iter().map(|col| &col.name).collect();
+        writeln!(self.buf, "schema {columns:?}").unwrap();
+        Ok(())
+    }
+
     fn dataset_end(&mut self) -> geozero::error::Result<()> {
         writeln!(self.buf, "dataset_end").unwrap();
         Ok(())